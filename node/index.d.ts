@@ -0,0 +1,37 @@
+export type OutputMode = "flat" | "spaced" | "lines" | "variant-lines";
+
+export interface SorterOptions {
+  /** Remove duplicate classes (default: true) */
+  removeDuplicates: boolean;
+  /** Collapse whitespace between classes to single spaces (default: true) */
+  normalizeWhitespace: boolean;
+  /** Wrap class lists longer than this many columns where the literal allows it (default: 0, disabled) */
+  printWidth: number;
+  /** How sorted classes are laid out (default: "flat") */
+  outputMode: OutputMode;
+  /** Extra JSX attributes whose values are always sorted, as in prettier-plugin-tailwindcss */
+  tailwindAttributes: string[];
+  /** Functions and template tags whose string arguments are always sorted, as in prettier-plugin-tailwindcss */
+  tailwindFunctions: string[];
+  /** Log debug output from the sorter */
+  debug: boolean;
+}
+
+export interface UnsortedClasses {
+  /** Byte offset of the literal start */
+  start: number;
+  /** Byte offset of the literal end */
+  end: number;
+  /** One-based line */
+  line: number;
+  /** One-based column */
+  column: number;
+  original: string;
+  expected: string;
+}
+
+export function configure(options?: Partial<SorterOptions>): SorterOptions;
+
+export function sortClasses(text: string, filePath: string | undefined, options?: Partial<SorterOptions>): string;
+
+export function checkClasses(text: string, filePath: string | undefined, options?: Partial<SorterOptions>): UnsortedClasses[];
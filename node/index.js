@@ -0,0 +1,90 @@
+"use strict";
+
+const path = require("node:path");
+const wasm = require("./wasm/digital_magistery_tailwind_sorter.js");
+
+// Library use must not write to stdout, so only log when debugging
+wasm.set_console_logging(false);
+
+/** @type {import("./index").SorterOptions} */
+const defaultOptions = {
+  removeDuplicates: true,
+  normalizeWhitespace: true,
+  printWidth: 0,
+  outputMode: "flat",
+  tailwindAttributes: [],
+  tailwindFunctions: [],
+  debug: false,
+};
+
+// The wasm sorter keeps its configuration globally, so only reconfigure when options change
+let configuredKey = null;
+
+/**
+ * @param {Partial<import("./index").SorterOptions>} [options]
+ * @returns {import("./index").SorterOptions}
+ */
+function configure(options = {}) {
+  const resolved = { ...defaultOptions, ...options };
+  const key = JSON.stringify(resolved);
+
+  if (key !== configuredKey) {
+    wasm.set_console_logging(resolved.debug);
+    wasm.set_log_level(resolved.debug ? "debug" : "info");
+    wasm.configure_tailwind_sorter(
+      resolved.removeDuplicates,
+      resolved.debug,
+      resolved.normalizeWhitespace,
+      resolved.printWidth,
+      resolved.outputMode
+    );
+    wasm.configure_class_locations(resolved.tailwindAttributes, resolved.tailwindFunctions);
+    configuredKey = key;
+  }
+
+  return resolved;
+}
+
+/**
+ * Pick the parser extension from a file path, defaulting to TSX which accepts the most syntax
+ *
+ * @param {string | undefined} filePath
+ * @returns {string}
+ */
+function fileExtension(filePath) {
+  const extension = filePath ? path.extname(filePath).slice(1) : "";
+  return extension || "tsx";
+}
+
+/**
+ * Sort the Tailwind classes in a (usually already formatted) document
+ *
+ * @param {string} text
+ * @param {string | undefined} filePath
+ * @param {Partial<import("./index").SorterOptions>} [options]
+ * @returns {string}
+ */
+function sortClasses(text, filePath, options) {
+  configure(options);
+  return wasm.sort_tailwind_classes(text, fileExtension(filePath));
+}
+
+/**
+ * Report unsorted class strings without changing the document.
+ * Throws when the document can't be parsed, so a broken file never passes as sorted.
+ *
+ * @param {string} text
+ * @param {string | undefined} filePath
+ * @param {Partial<import("./index").SorterOptions>} [options]
+ * @returns {import("./index").UnsortedClasses[]}
+ */
+function checkClasses(text, filePath, options) {
+  configure(options);
+  return wasm.check_tailwind_classes(text, fileExtension(filePath));
+}
+
+module.exports = {
+  configure,
+  sortClasses,
+  checkClasses,
+};
@@ -0,0 +1,10 @@
+import type { Parser, Printer, SupportOptions } from "prettier";
+import type { SorterOptions } from "./index";
+
+export const options: SupportOptions;
+
+export const parsers: Record<"babel" | "babel-flow" | "babel-ts" | "typescript", Parser>;
+
+export const printers: Record<"tailwind-sorter-estree", Printer>;
+
+export function sorterOptions(prettierOptions: Record<string, unknown>): Partial<SorterOptions>;
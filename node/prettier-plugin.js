@@ -0,0 +1,112 @@
+"use strict";
+
+const { sortClasses } = require("./index.js");
+const { printer: docPrinter } = require("prettier/doc");
+const { parsers: babelParsers } = require("prettier/plugins/babel");
+const { printers: estreePrinters } = require("prettier/plugins/estree");
+const { parsers: typescriptParsers } = require("prettier/plugins/typescript");
+
+// AST format of the wrapped parsers, so only their output goes through the sorting printer
+const astFormat = "tailwind-sorter-estree";
+
+// Parsers that handle TypeScript syntax, used to pick an extension when there is no file path
+const typescriptParserNames = new Set(["typescript", "babel-ts"]);
+
+/** @type {import("prettier").SupportOptions} */
+const options = {
+  tailwindAttributes: {
+    type: "string",
+    array: true,
+    default: [{ value: [] }],
+    category: "Tailwind CSS",
+    description: "List of attributes/props that contain sortable Tailwind classes",
+  },
+  tailwindFunctions: {
+    type: "string",
+    array: true,
+    default: [{ value: [] }],
+    category: "Tailwind CSS",
+    description: "List of functions and tagged templates that contain sortable Tailwind classes",
+  },
+  tailwindPreserveWhitespace: {
+    type: "boolean",
+    default: false,
+    category: "Tailwind CSS",
+    description: "Preserve whitespace around Tailwind classes when sorting",
+  },
+  tailwindPreserveDuplicates: {
+    type: "boolean",
+    default: false,
+    category: "Tailwind CSS",
+    description: "Preserve duplicate classes inside a class list when sorting",
+  },
+};
+
+/**
+ * Map Prettier options onto sorter options
+ *
+ * @param {Record<string, unknown>} prettierOptions
+ * @returns {Partial<import("./index").SorterOptions>}
+ */
+function sorterOptions(prettierOptions) {
+  return {
+    tailwindAttributes: /** @type {string[]} */ (prettierOptions.tailwindAttributes ?? []),
+    tailwindFunctions: /** @type {string[]} */ (prettierOptions.tailwindFunctions ?? []),
+    normalizeWhitespace: !prettierOptions.tailwindPreserveWhitespace,
+    removeDuplicates: !prettierOptions.tailwindPreserveDuplicates,
+  };
+}
+
+/**
+ * Wrap a built-in parser so its AST is printed by the sorting printer.
+ *
+ * @param {import("prettier").Parser} parser
+ * @returns {import("prettier").Parser}
+ */
+function withClassSorting(parser) {
+  return { ...parser, astFormat };
+}
+
+/**
+ * Wrap the estree printer so class strings are sorted in the printed output.
+ *
+ * Prettier has no hook that runs after printing, so the root node prints the whole
+ * document to a string, sorts it, and hands the sorted text back as its doc.
+ *
+ * @param {import("prettier").Printer} printer
+ * @returns {import("prettier").Printer}
+ */
+function withSortedOutput(printer) {
+  return {
+    ...printer,
+    print(path, printerOptions, print, args) {
+      const doc = printer.print(path, printerOptions, print, args);
+      if (path.stack.length > 1) {
+        return doc;
+      }
+
+      const { formatted } = docPrinter.printDocToString(doc, printerOptions);
+      const typescript = typescriptParserNames.has(/** @type {string} */ (printerOptions.parser));
+      const filePath = printerOptions.filepath || (typescript ? "file.tsx" : "file.jsx");
+      return sortClasses(formatted, filePath, sorterOptions(printerOptions));
+    },
+  };
+}
+
+const parsers = {
+  babel: withClassSorting(babelParsers.babel),
+  "babel-flow": withClassSorting(babelParsers["babel-flow"]),
+  "babel-ts": withClassSorting(babelParsers["babel-ts"]),
+  typescript: withClassSorting(typescriptParsers.typescript),
+};
+
+const printers = {
+  [astFormat]: withSortedOutput(estreePrinters.estree),
+};
+
+module.exports = {
+  options,
+  parsers,
+  printers,
+  sorterOptions,
+};
@@ -0,0 +1,46 @@
+"use strict";
+
+const assert = require("node:assert/strict");
+const { test } = require("node:test");
+const { checkClasses, sortClasses } = require("../index.js");
+
+test("sorts classes in formatted text", () => {
+  const text = 'export const A = () => <div className="p-4 flex mt-2" />;\n';
+
+  assert.equal(sortClasses(text, "src/A.tsx"), 'export const A = () => <div className="mt-2 flex p-4" />;\n');
+});
+
+test("honors tailwindFunctions and tailwindAttributes", () => {
+  // Too few Tailwind classes for the heuristic, but sorted inside a configured function
+  const mostlyCustom = 'const a = styles("p-4 mt-2 card header title body");\n';
+  assert.equal(sortClasses(mostlyCustom, "src/a.ts"), mostlyCustom);
+  assert.notEqual(sortClasses(mostlyCustom, "src/a.ts", { tailwindFunctions: ["styles"] }), mostlyCustom);
+
+  const attribute = 'const b = <Button tw="p-4 mt-2 card header title body" />;\n';
+  assert.equal(checkClasses(attribute, "src/b.tsx").length, 0);
+  assert.equal(checkClasses(attribute, "src/b.tsx", { tailwindAttributes: ["tw"] }).length, 1);
+});
+
+test("reports unsorted class strings", () => {
+  const [unsorted] = checkClasses('const a = "p-4 flex mt-2";\n', "a.ts", {});
+
+  assert.equal(unsorted.line, 1);
+  assert.equal(unsorted.expected, "mt-2 flex p-4");
+});
+
+// Prettier is an optional peer dependency, so the plugin test only runs when it is installed
+let prettier = null;
+try {
+  prettier = require("prettier");
+} catch {
+  prettier = null;
+}
+
+test("prettier plugin sorts the printed output", { skip: prettier === null }, async () => {
+  const plugin = require("../prettier-plugin.js");
+  const text = 'const A = () => <div className="p-4   flex mt-2"/>\n';
+
+  const formatted = await prettier.format(text, { parser: "babel", plugins: [plugin] });
+
+  assert.equal(formatted, 'const A = () => <div className="mt-2 flex p-4" />;\n');
+});
@@ -1,5 +1,5 @@
 import * as vscode from "vscode";
-import type { TailwindSorterConfig } from "./utils/types";
+import type { InternalSorterOutputMode, TailwindSorterConfig } from "./utils/types";
 
 export function getConfig(): TailwindSorterConfig {
   const config = vscode.workspace.getConfiguration("tailwindSorter");
@@ -15,6 +15,9 @@ export function getConfig(): TailwindSorterConfig {
       debug: config.get("internalSorter.debug", false),
       removeDuplicateClasses: config.get("internalSorter.removeDuplicateClasses", true),
       normalizeWhitespace: config.get("internalSorter.normalizeWhitespace", true),
+      printWidth: config.get("internalSorter.printWidth", 0),
+      outputMode: config.get<InternalSorterOutputMode>("internalSorter.outputMode", "flat"),
+      screens: config.get<Record<string, string>>("internalSorter.screens", {}),
     },
   };
 }
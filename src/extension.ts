@@ -37,6 +37,21 @@ export async function activate(context: vscode.ExtensionContext) {
     }
   );
 
+  // Register the range formatting provider (internal sorter only)
+  const rangeFormattingProvider = vscode.languages.registerDocumentRangeFormattingEditProvider(
+    { scheme: "file" },
+    {
+      provideDocumentRangeFormattingEdits: async (document: vscode.TextDocument, range: vscode.Range) => {
+        logger.debugLog(`Range formatting requested for: ${document.fileName}`);
+        if (!formatter.shouldFormatDocument(document)) {
+          logger.debugLog(`Skipping format for unsupported file: ${document.fileName}`);
+          return undefined;
+        }
+        return await formatter.formatRanges(document, [range]);
+      },
+    }
+  );
+
   // Handle format on save
   const saveDisposable = vscode.workspace.onWillSaveTextDocument((e) => {
     if (!formatter.shouldFormatDocument(e.document)) {
@@ -106,6 +121,7 @@ export async function activate(context: vscode.ExtensionContext) {
     formatter,
     configurationChangeDisposable,
     formattingProvider,
+    rangeFormattingProvider,
     saveDisposable,
     showOutputDisposable,
     testFormatterDisposable
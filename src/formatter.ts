@@ -4,7 +4,7 @@ import { getConfig } from "./config";
 import { RustywindManager } from "./rustywind";
 import type { Logger } from "./utils/logging";
 import type { DiagnosticResult, ExecFunction, TailwindSorterConfig } from "./utils/types";
-import { configureWasmSorter, initWasmSorter, sortClassesWithWasm } from "./wasmSorter";
+import { configureWasmSorter, initWasmSorter, sortClassRangesWithWasm, sortClassesWithWasm } from "./wasmSorter";
 
 export class TailwindSorterFormatter implements vscode.Disposable {
   private statusBarItem: vscode.StatusBarItem;
@@ -98,7 +98,10 @@ export class TailwindSorterFormatter implements vscode.Disposable {
       this.config.internalSorter.enabled &&
       (oldConfig.internalSorter.removeDuplicateClasses !== this.config.internalSorter.removeDuplicateClasses ||
         oldConfig.internalSorter.debug !== this.config.internalSorter.debug ||
-        oldConfig.internalSorter.normalizeWhitespace !== this.config.internalSorter.normalizeWhitespace)
+        oldConfig.internalSorter.normalizeWhitespace !== this.config.internalSorter.normalizeWhitespace ||
+        oldConfig.internalSorter.printWidth !== this.config.internalSorter.printWidth ||
+        oldConfig.internalSorter.outputMode !== this.config.internalSorter.outputMode ||
+        JSON.stringify(oldConfig.internalSorter.screens) !== JSON.stringify(this.config.internalSorter.screens))
     ) {
       configureWasmSorter(this.config, this.logger);
     }
@@ -172,6 +175,49 @@ export class TailwindSorterFormatter implements vscode.Disposable {
     }
   }
 
+  public async formatRanges(document: vscode.TextDocument, ranges: readonly vscode.Range[]): Promise<vscode.TextEdit[] | undefined> {
+    if (!this.config.enable) {
+      this.logger.debugLog("Tailwind Sorter is disabled in settings");
+      return;
+    }
+
+    // RustyWind can only sort whole files
+    if (!this.config.internalSorter.enabled) {
+      this.logger.debugLog("Range formatting requires the internal sorter");
+      return;
+    }
+
+    const fileName = document.fileName;
+    if (!this.isFileIncluded(fileName)) {
+      this.logger.debugLog(`File ${fileName} is not a supported file type`);
+      return;
+    }
+
+    const text = document.getText();
+    const offsetRanges = ranges.map((range) => ({
+      start: document.offsetAt(range.start),
+      end: document.offsetAt(range.end),
+    }));
+
+    try {
+      const edits = await sortClassRangesWithWasm(text, fileName, offsetRanges, this.logger, this.config);
+
+      if (edits.length === 0) {
+        this.logger.debugLog("No changes needed - classes in range already sorted");
+        return;
+      }
+
+      // Only replace the rewritten class strings so edits elsewhere in the document survive
+      this.logger.debugLog("Classes in range sorted successfully with internal sorter");
+      return edits.map((edit) => {
+        const range = new vscode.Range(document.positionAt(edit.start), document.positionAt(edit.end));
+        return vscode.TextEdit.replace(range, edit.replacement);
+      });
+    } catch (error) {
+      this.handleFormatError(fileName, error);
+    }
+  }
+
   dispose(): void {
     this.statusBarItem.dispose();
   }
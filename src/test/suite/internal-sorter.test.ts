@@ -24,6 +24,9 @@ suite("TailwindSorterFormatter - Internal Sorter", function () {
       debug: false,
       removeDuplicateClasses: true,
       normalizeWhitespace: true,
+      printWidth: 0,
+      outputMode: "flat",
+      screens: {},
     };
   });
 
@@ -1,10 +1,15 @@
 import type { ExecOptions } from "node:child_process";
 
+export type InternalSorterOutputMode = "flat" | "spaced" | "lines" | "variant-lines";
+
 export interface InternalSorterConfig {
   enabled: boolean;
   debug: boolean;
   removeDuplicateClasses: boolean;
   normalizeWhitespace: boolean;
+  printWidth: number;
+  outputMode: InternalSorterOutputMode;
+  screens: Record<string, string>;
 }
 
 export interface TailwindSorterConfig {
@@ -17,6 +22,17 @@ export interface TailwindSorterConfig {
   internalSorter: InternalSorterConfig;
 }
 
+export interface OffsetRange {
+  start: number;
+  end: number;
+}
+
+export interface OffsetEdit {
+  start: number;
+  end: number;
+  replacement: string;
+}
+
 export interface TempFileOptions {
   prefix?: string;
   extension?: string;
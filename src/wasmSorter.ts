@@ -1,15 +1,37 @@
 import type { Logger } from "./utils/logging";
-import type { TailwindSorterConfig } from "./utils/types";
+import type { OffsetEdit, OffsetRange, TailwindSorterConfig } from "./utils/types";
 
 let wasmSorter: {
   sort_tailwind_classes: (document: string, fileExtension: string) => string;
-  configure_tailwind_sorter: (removeDuplicates: boolean, debugMode: boolean, normalizeWhitespace: boolean) => void;
+  sort_tailwind_classes_in_ranges: (document: string, fileExtension: string, ranges: OffsetRange[], utf16: boolean) => OffsetEdit[];
+  configure_tailwind_sorter: (
+    removeDuplicates: boolean,
+    debugMode: boolean,
+    normalizeWhitespace: boolean,
+    printWidth: number,
+    outputMode: string
+  ) => void;
+  configure_screens: (screens: Record<string, string>) => void;
+  set_log_callback: (callback?: (level: WasmLogLevel, message: string) => void) => void;
+  set_log_level: (level: WasmLogLevel | "off") => void;
 } | null = null;
 
+type WasmLogLevel = "error" | "warn" | "info" | "debug" | "trace";
+
+// Errors and warnings always reach the output channel, everything else only in debug mode
+function forwardWasmLog(logger: Logger, level: WasmLogLevel, message: string): void {
+  if (level === "error" || level === "warn") {
+    logger.log(`[${level.toUpperCase()} - Internal Sorter] ${message}`);
+  } else {
+    logger.debugLog(`[Internal Sorter] ${message}`);
+  }
+}
+
 export async function initWasmSorter(logger: Logger, config: TailwindSorterConfig): Promise<void> {
   try {
     const wasm = await import("../out/wasm/digital_magistery_tailwind_sorter.js");
     wasmSorter = wasm;
+    wasm.set_log_callback((level, message) => forwardWasmLog(logger, level, message));
 
     // Initialize configuration
     configureWasmSorter(config, logger);
@@ -30,12 +52,18 @@ export function configureWasmSorter(config: TailwindSorterConfig, logger: Logger
   const removeDuplicates = config.internalSorter.removeDuplicateClasses;
   const debugMode = config.internalSorter.debug;
   const normalizeWhitespace = config.internalSorter.normalizeWhitespace;
+  const printWidth = config.internalSorter.printWidth;
+  const outputMode = config.internalSorter.outputMode;
 
   logger.debugLog(
-    `Configuring WASM sorter with removeDuplicateClasses=${removeDuplicates}, debug=${debugMode}, normalizeWhitespace=${normalizeWhitespace}`
+    `Configuring WASM sorter with removeDuplicateClasses=${removeDuplicates}, debug=${debugMode}, normalizeWhitespace=${normalizeWhitespace}, printWidth=${printWidth}, outputMode=${outputMode}`
   );
 
-  wasmSorter.configure_tailwind_sorter(removeDuplicates, debugMode, normalizeWhitespace);
+  wasmSorter.configure_tailwind_sorter(removeDuplicates, debugMode, normalizeWhitespace, printWidth, outputMode);
+  wasmSorter.configure_screens(config.internalSorter.screens);
+
+  // Skip formatting messages the output channel would drop anyway
+  wasmSorter.set_log_level(debugMode ? "debug" : "warn");
 }
 
 export async function sortClassesWithWasm(document: string, fileName: string, logger: Logger, config: TailwindSorterConfig): Promise<string> {
@@ -58,3 +86,31 @@ export async function sortClassesWithWasm(document: string, fileName: string, lo
     throw new Error(`Internal Tailwind class sorter error: ${error}`);
   }
 }
+
+export async function sortClassRangesWithWasm(
+  document: string,
+  fileName: string,
+  ranges: OffsetRange[],
+  logger: Logger,
+  config: TailwindSorterConfig
+): Promise<OffsetEdit[]> {
+  if (!wasmSorter) {
+    logger.debugLog("WASM sorter not initialized");
+    throw new Error("Internal sorter not initialized");
+  }
+
+  try {
+    const fileExtension = fileName.split(".").pop() || "";
+
+    logger.debugLog(`Sorting Tailwind classes in ${ranges.length} range(s) of ${fileName} with internal sorter`);
+
+    // VS Code offsets are UTF-16 code units
+    const edits = wasmSorter.sort_tailwind_classes_in_ranges(document, fileExtension, ranges, true);
+
+    logger.debugLog(`Sorted ${edits.length} class string(s) in range with internal sorter`);
+    return edits;
+  } catch (error) {
+    logger.debugLog(`Error during internal range sorting: ${error}`);
+    throw new Error(`Internal Tailwind class sorter error: ${error}`);
+  }
+}
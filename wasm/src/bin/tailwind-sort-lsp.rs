@@ -0,0 +1,31 @@
+use std::process::ExitCode;
+
+use digital_magistery_tailwind_sorter::lsp::run_stdio;
+
+const USAGE: &str = "Usage: tailwind-sort-lsp
+
+Run a language server for Tailwind CSS class sorting over stdin and stdout.
+Supports document and range formatting, diagnostics for unsorted class strings
+and \"Sort Tailwind classes\" code actions.
+
+Sorter options can be passed as initializationOptions:
+  removeDuplicates, normalizeWhitespace, printWidth, outputMode";
+
+fn main() -> ExitCode {
+    if std::env::args()
+        .skip(1)
+        .any(|arg| arg == "-h" || arg == "--help")
+    {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    match run_stdio() {
+        Ok(0) => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,475 @@
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use digital_magistery_tailwind_sorter::check::check_classes;
+use digital_magistery_tailwind_sorter::diff::{DiffOptions, unified_diff};
+use digital_magistery_tailwind_sorter::extractor::ExtractError;
+use digital_magistery_tailwind_sorter::report::{
+    Finding, ReportFormat, find_unsorted, render_report,
+};
+use digital_magistery_tailwind_sorter::sorter::{
+    OutputMode, set_normalize_whitespace, set_output_mode, set_print_width, set_remove_duplicates,
+};
+use digital_magistery_tailwind_sorter::tailwind_config::parse_tailwind_config;
+use digital_magistery_tailwind_sorter::theme::{Theme, apply_theme, parse_css_theme};
+use digital_magistery_tailwind_sorter::try_sort_document;
+
+const USAGE: &str = "Usage: tailwind-sort [OPTIONS] <FILE|GLOB|->...
+
+Sort Tailwind CSS classes in JavaScript and TypeScript files.
+Without --write, --check or --diff, sorted output is printed to stdout.
+
+Options:
+  -w, --write                Rewrite files in place
+  -c, --check                Report unsorted files and exit with status 1 if any are found
+  -d, --diff                 Print a unified diff for unsorted files and exit with status 1 if any
+      --color                Color diff output and highlight moved classes
+      --format <FORMAT>      Check output format: text, json or sarif (default: text)
+      --ext <EXT>            File extension used to parse stdin (default: tsx)
+      --keep-duplicates      Keep duplicate classes
+      --preserve-whitespace  Keep the original whitespace between classes
+      --print-width <N>      Wrap class lists longer than N columns where possible
+      --output-mode <MODE>   flat, spaced, lines or variant-lines (default: flat)
+      --css <FILE>           Load theme, utilities and variants from a Tailwind CSS entry file
+      --config <FILE>        Load theme, prefix and separator from a tailwind.config.js or .ts file
+  -h, --help                 Print this help";
+
+/// Exit status when unsorted files are found in check mode
+const EXIT_UNSORTED: u8 = 1;
+/// Exit status for usage and I/O errors, and for files that can't be parsed
+const EXIT_ERROR: u8 = 2;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Options {
+    inputs: Vec<String>,
+    write: bool,
+    check: bool,
+    diff: bool,
+    color: bool,
+    report_format: Option<ReportFormat>,
+    stdin_extension: String,
+    remove_duplicates: bool,
+    normalize_whitespace: bool,
+    print_width: usize,
+    output_mode: OutputMode,
+    stylesheet: Option<PathBuf>,
+    config: Option<PathBuf>,
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        inputs: Vec::new(),
+        write: false,
+        check: false,
+        diff: false,
+        color: false,
+        report_format: None,
+        stdin_extension: "tsx".to_string(),
+        remove_duplicates: true,
+        normalize_whitespace: true,
+        print_width: 0,
+        output_mode: OutputMode::Flat,
+        stylesheet: None,
+        config: None,
+    };
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        // Support both `--flag value` and `--flag=value`
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag.to_string(), Some(value)),
+            _ => (arg.clone(), None),
+        };
+        let mut value = |name: &str| -> Result<String, String> {
+            inline_value
+                .map(str::to_string)
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("missing value for {}", name))
+        };
+
+        match flag.as_str() {
+            "-w" | "--write" => options.write = true,
+            "-c" | "--check" => options.check = true,
+            "-d" | "--diff" => options.diff = true,
+            "--color" => options.color = true,
+            "--format" => {
+                let format = value("--format")?;
+                options.report_format = match format.as_str() {
+                    "text" => None,
+                    _ => Some(
+                        ReportFormat::from_name(&format)
+                            .ok_or_else(|| format!("invalid format: {}", format))?,
+                    ),
+                };
+            }
+            "--ext" => {
+                options.stdin_extension = value("--ext")?.trim_start_matches('.').to_string()
+            }
+            "--keep-duplicates" => options.remove_duplicates = false,
+            "--preserve-whitespace" => options.normalize_whitespace = false,
+            "--print-width" => {
+                let width = value("--print-width")?;
+                options.print_width = width
+                    .parse()
+                    .map_err(|_| format!("invalid print width: {}", width))?;
+            }
+            "--output-mode" => {
+                let mode = value("--output-mode")?;
+                options.output_mode = OutputMode::from_name(&mode)
+                    .ok_or_else(|| format!("invalid output mode: {}", mode))?;
+            }
+            "--css" => options.stylesheet = Some(PathBuf::from(value("--css")?)),
+            "--config" => options.config = Some(PathBuf::from(value("--config")?)),
+            "-" => options.inputs.push(arg),
+            _ if flag.starts_with('-') => return Err(format!("unknown option: {}", arg)),
+            _ => options.inputs.push(arg),
+        }
+    }
+
+    if [options.write, options.check, options.diff]
+        .iter()
+        .filter(|&&mode| mode)
+        .count()
+        > 1
+    {
+        return Err("--write, --check and --diff cannot be used together".to_string());
+    }
+
+    if options.report_format.is_some() && !options.check {
+        return Err("--format requires --check".to_string());
+    }
+
+    if options.inputs.is_empty() {
+        return Err("no input files given (use - to read from stdin)".to_string());
+    }
+
+    Ok(options)
+}
+
+/// Expand glob patterns into file paths, keeping plain paths as they are
+fn expand_inputs(inputs: &[String]) -> Result<Vec<PathBuf>, String> {
+    let mut paths = Vec::new();
+
+    for input in inputs {
+        if !input.contains(['*', '?', '[']) {
+            paths.push(PathBuf::from(input));
+            continue;
+        }
+
+        let entries = glob::glob(input).map_err(|e| format!("invalid glob {}: {}", input, e))?;
+        let mut matched = false;
+        for entry in entries {
+            let path = entry.map_err(|e| e.to_string())?;
+            if path.is_file() {
+                paths.push(path);
+                matched = true;
+            }
+        }
+
+        if !matched {
+            return Err(format!("no files match {}", input));
+        }
+    }
+
+    Ok(paths)
+}
+
+fn file_extension(path: &Path) -> &str {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default()
+}
+
+/// What processing a document found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Sorted,
+    Unsorted,
+    /// The document could not be parsed, or its extension is not supported
+    Unreadable,
+}
+
+/// Report every unsorted class string in a document, returning whether there were any.
+///
+/// Findings are collected for machine-readable reports and printed right away otherwise.
+fn report_unsorted(
+    name: &str,
+    document: &str,
+    extension: &str,
+    options: &Options,
+    findings: &mut Vec<Finding>,
+) -> Result<bool, ExtractError> {
+    if options.report_format.is_some() {
+        let found = find_unsorted(document, extension, name)?;
+        let any_found = !found.is_empty();
+        findings.extend(found);
+        return Ok(any_found);
+    }
+
+    let unsorted = check_classes(document, extension)?;
+
+    for entry in &unsorted {
+        eprintln!(
+            "{}:{}:{}: unsorted classes \"{}\", expected \"{}\"",
+            name, entry.line, entry.column, entry.original, entry.expected
+        );
+    }
+
+    Ok(!unsorted.is_empty())
+}
+
+/// Check, diff, write or print one document, depending on the mode.
+///
+/// `path` is where `--write` stores the sorted document; stdin has none. Documents that
+/// can't be parsed are reported on stderr and left alone.
+fn process_document(
+    name: &str,
+    path: Option<&Path>,
+    document: &str,
+    extension: &str,
+    options: &Options,
+    findings: &mut Vec<Finding>,
+) -> Result<Outcome, String> {
+    let unreadable = |error: ExtractError| {
+        eprintln!("{}: {}", name, error);
+        Ok(Outcome::Unreadable)
+    };
+    let outcome = |unsorted: bool| {
+        if unsorted {
+            Outcome::Unsorted
+        } else {
+            Outcome::Sorted
+        }
+    };
+
+    if options.check {
+        return match report_unsorted(name, document, extension, options, findings) {
+            Ok(unsorted) => Ok(outcome(unsorted)),
+            Err(error) => unreadable(error),
+        };
+    }
+
+    let sorted = match try_sort_document(document, extension) {
+        Ok(sorted) => sorted,
+        Err(error) => return unreadable(error),
+    };
+
+    if options.diff {
+        return print_diff(name, document, &sorted, options.color).map(outcome);
+    }
+
+    match path {
+        Some(path) if options.write => {
+            if sorted != document {
+                fs::write(path, &sorted).map_err(|e| format!("failed to write {}: {}", name, e))?;
+                eprintln!("Sorted {}", name);
+            }
+        }
+        _ => io::stdout()
+            .write_all(sorted.as_bytes())
+            .map_err(|e| format!("failed to write stdout: {}", e))?,
+    }
+
+    Ok(Outcome::Sorted)
+}
+
+/// Print a unified diff of the changes sorting would make to a document
+fn print_diff(name: &str, document: &str, sorted: &str, color: bool) -> Result<bool, String> {
+    let diff_options = DiffOptions {
+        color,
+        ..DiffOptions::default()
+    };
+    let diff = unified_diff(name, document, sorted, &diff_options);
+
+    io::stdout()
+        .write_all(diff.as_bytes())
+        .map_err(|e| format!("failed to write stdout: {}", e))?;
+
+    Ok(!diff.is_empty())
+}
+
+/// Read the theme of a Tailwind CSS entry file or config, printing anything it could not read
+fn read_theme(path: &Path, parse: impl Fn(&str) -> Theme) -> Result<Theme, String> {
+    let source = fs::read_to_string(path)
+        .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+    let mut theme = parse(&source);
+    for warning in theme.warnings.drain(..) {
+        eprintln!("warning: {}: {}", path.display(), warning);
+    }
+    Ok(theme)
+}
+
+fn run(options: &Options) -> Result<bool, String> {
+    // Combine the config and the CSS entry file first, so neither resets the other
+    let mut theme = None;
+    if let Some(config) = &options.config {
+        theme = Some(read_theme(config, |source| {
+            parse_tailwind_config(source, file_extension(config))
+        })?);
+    }
+    if let Some(stylesheet) = &options.stylesheet {
+        let css = read_theme(stylesheet, parse_css_theme)?;
+        theme = Some(match theme {
+            Some(config) => config.merge(css),
+            None => css,
+        });
+    }
+    if let Some(theme) = &theme {
+        apply_theme(theme);
+    }
+
+    let mut found_unsorted = false;
+    let mut unreadable = 0;
+    let mut findings = Vec::new();
+    let mut record = |outcome: Outcome| match outcome {
+        Outcome::Sorted => {}
+        Outcome::Unsorted => found_unsorted = true,
+        Outcome::Unreadable => unreadable += 1,
+    };
+
+    for input in &options.inputs {
+        if input != "-" {
+            continue;
+        }
+
+        let mut document = String::new();
+        io::stdin()
+            .read_to_string(&mut document)
+            .map_err(|e| format!("failed to read stdin: {}", e))?;
+
+        record(process_document(
+            "<stdin>",
+            None,
+            &document,
+            &options.stdin_extension,
+            options,
+            &mut findings,
+        )?);
+    }
+
+    let file_inputs: Vec<String> = options
+        .inputs
+        .iter()
+        .filter(|input| *input != "-")
+        .cloned()
+        .collect();
+
+    for path in expand_inputs(&file_inputs)? {
+        let name = path.display().to_string();
+        let document =
+            fs::read_to_string(&path).map_err(|e| format!("failed to read {}: {}", name, e))?;
+        let extension = file_extension(&path);
+
+        record(process_document(
+            &name,
+            Some(&path),
+            &document,
+            extension,
+            options,
+            &mut findings,
+        )?);
+    }
+
+    if let Some(format) = options.report_format {
+        let report = render_report(&findings, format);
+        writeln!(io::stdout(), "{}", report)
+            .map_err(|e| format!("failed to write stdout: {}", e))?;
+    }
+
+    // Unreadable files must not pass as sorted
+    if unreadable > 0 {
+        return Err(format!("{} file(s) could not be parsed", unreadable));
+    }
+
+    Ok(found_unsorted)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    let options = match parse_args(args) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("error: {}\n\n{}", error, USAGE);
+            return ExitCode::from(EXIT_ERROR);
+        }
+    };
+
+    set_remove_duplicates(options.remove_duplicates);
+    set_normalize_whitespace(options.normalize_whitespace);
+    set_print_width(options.print_width);
+    set_output_mode(options.output_mode);
+
+    match run(&options) {
+        Ok(true) => ExitCode::from(EXIT_UNSORTED),
+        Ok(false) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args_defaults() {
+        let options = parse_args(args(&["src/app.tsx"])).unwrap();
+        assert_eq!(options.inputs, vec!["src/app.tsx"]);
+        assert!(!options.write && !options.check);
+        assert_eq!(options.stdin_extension, "tsx");
+        assert!(options.remove_duplicates && options.normalize_whitespace);
+    }
+
+    #[test]
+    fn test_parse_args_flags() {
+        let options = parse_args(args(&[
+            "--check",
+            "--ext=.jsx",
+            "--print-width",
+            "100",
+            "--output-mode=lines",
+            "--format",
+            "sarif",
+            "--css=app.css",
+            "--config",
+            "tailwind.config.ts",
+            "-",
+        ]))
+        .unwrap();
+        assert!(options.check);
+        assert_eq!(options.stdin_extension, "jsx");
+        assert_eq!(options.print_width, 100);
+        assert_eq!(options.output_mode, OutputMode::Lines);
+        assert_eq!(options.report_format, Some(ReportFormat::Sarif));
+        assert_eq!(options.stylesheet, Some(PathBuf::from("app.css")));
+        assert_eq!(options.config, Some(PathBuf::from("tailwind.config.ts")));
+        assert_eq!(options.inputs, vec!["-"]);
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(parse_args(args(&[])).is_err());
+        assert!(parse_args(args(&["--write", "--check", "a.tsx"])).is_err());
+        assert!(parse_args(args(&["--check", "--diff", "a.tsx"])).is_err());
+        assert!(parse_args(args(&["--format", "sarif", "a.tsx"])).is_err());
+        assert!(parse_args(args(&["--check", "--format", "xml", "a.tsx"])).is_err());
+        assert!(parse_args(args(&["--unknown", "a.tsx"])).is_err());
+        assert!(parse_args(args(&["--print-width", "wide", "a.tsx"])).is_err());
+        assert!(parse_args(args(&["--ext"])).is_err());
+        assert!(parse_args(args(&["a.tsx", "--css"])).is_err());
+    }
+}
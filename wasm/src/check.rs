@@ -0,0 +1,106 @@
+use serde::Serialize;
+
+use crate::debug_log;
+use crate::extractor::{ExtractError, try_extract_class_strings};
+use crate::layout::sort_class_match;
+
+/// A class string that is not in sorted order
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UnsortedClasses {
+    /// Starting byte offset of the literal in the document
+    pub start: usize,
+    /// Ending byte offset of the literal in the document
+    pub end: usize,
+    /// One-based line of the literal's start
+    pub line: usize,
+    /// One-based column (in characters) of the literal's start
+    pub column: usize,
+    /// The class string as it appears in the document
+    pub original: String,
+    /// The class string the sorter would write instead
+    pub expected: String,
+}
+
+/// Find every class string that the sorter would rewrite, in document order.
+///
+/// Fails when the document can't be parsed, so a broken file never passes as sorted.
+pub fn check_classes(
+    document: &str,
+    file_extension: &str,
+) -> Result<Vec<UnsortedClasses>, ExtractError> {
+    let mut class_matches = try_extract_class_strings(document, file_extension)?;
+    class_matches.sort_by_key(|m| m.start);
+
+    let unsorted: Vec<UnsortedClasses> = class_matches
+        .iter()
+        .filter_map(|class_match| {
+            let expected = sort_class_match(document, class_match);
+            if expected == class_match.class_string {
+                return None;
+            }
+
+            let (line, column) = line_column(document, class_match.start);
+            Some(UnsortedClasses {
+                start: class_match.start,
+                end: class_match.end,
+                line,
+                column,
+                original: class_match.class_string.clone(),
+                expected,
+            })
+        })
+        .collect();
+
+    debug_log!("Found {} unsorted class strings", unsorted.len());
+
+    Ok(unsorted)
+}
+
+/// Get the one-based line and column of a byte offset
+pub fn line_column(document: &str, offset: usize) -> (usize, usize) {
+    let offset = offset.min(document.len());
+    let before = &document[..offset];
+    let line_start = before.rfind('\n').map_or(0, |pos| pos + 1);
+
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_column() {
+        let document = "ab\ncdé\nf";
+        assert_eq!(line_column(document, 0), (1, 1));
+        assert_eq!(line_column(document, 4), (2, 2));
+        assert_eq!(line_column(document, document.len()), (3, 2));
+    }
+
+    #[test]
+    fn test_check_reports_unsorted_class_strings() {
+        let document = "const a = \"mt-2 flex p-4\";\nconst b = \"p-4 flex mt-2\";\n";
+        let unsorted = check_classes(document, "ts").unwrap();
+
+        assert_eq!(
+            unsorted,
+            vec![UnsortedClasses {
+                start: 37,
+                end: 52,
+                line: 2,
+                column: 11,
+                original: "p-4 flex mt-2".to_string(),
+                expected: "mt-2 flex p-4".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_check_fails_on_unparsable_document() {
+        let result = check_classes("const a = <div className=\"p-4 flex\"", "tsx");
+        assert!(matches!(result, Err(ExtractError::Parse(_))));
+    }
+}
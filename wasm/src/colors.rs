@@ -0,0 +1,96 @@
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+/// Colors of the default Tailwind v4 palette
+const PALETTE: &[&str] = &[
+    "red", "orange", "amber", "yellow", "lime", "green", "emerald", "teal", "cyan", "sky", "blue",
+    "indigo", "violet", "purple", "fuchsia", "pink", "rose", "slate", "gray", "zinc", "neutral",
+    "stone", "mauve", "olive", "mist", "taupe",
+];
+
+/// Shades every palette color comes in
+const SHADES: &[&str] = &[
+    "50", "100", "200", "300", "400", "500", "600", "700", "800", "900", "950",
+];
+
+/// Colors every color utility accepts, independent of the palette
+pub const COLOR_KEYWORDS: &[&str] = &["inherit", "current", "transparent", "black", "white"];
+
+#[derive(Debug, Default)]
+struct ThemeColors {
+    names: Vec<String>,
+    replace_palette: bool,
+}
+
+/// Color names defined by the project's theme, e.g. `brand` or `brand-500`
+static THEME_COLORS: OnceLock<Mutex<ThemeColors>> = OnceLock::new();
+
+/// Set the project's color names, either alongside Tailwind's palette or instead of it
+pub fn set_theme_colors(colors: Vec<String>, replace_palette: bool) {
+    let mutex = THEME_COLORS.get_or_init(|| Mutex::new(ThemeColors::default()));
+    if let Ok(mut value) = mutex.lock() {
+        value.names = colors;
+        value.replace_palette = replace_palette;
+    }
+}
+
+/// Check for a palette color with a known shade, e.g. `red-500` or `taupe-950`
+fn is_palette_color(value: &str, replace_palette: bool) -> bool {
+    !replace_palette
+        && value
+            .rsplit_once('-')
+            .is_some_and(|(color, shade)| PALETTE.contains(&color) && SHADES.contains(&shade))
+}
+
+/// Check whether a utility value names a color
+pub fn is_color(value: &str) -> bool {
+    let mutex = THEME_COLORS.get_or_init(|| Mutex::new(ThemeColors::default()));
+    match mutex.lock() {
+        Ok(theme_colors) => is_color_in(value, &theme_colors.names, theme_colors.replace_palette),
+        Err(_) => is_color_in(value, &[], false),
+    }
+}
+
+/// Check whether a utility value names a color, given the theme's color names and whether
+/// they replace the default palette
+fn is_color_in(value: &str, theme_colors: &[String], replace_palette: bool) -> bool {
+    COLOR_KEYWORDS.contains(&value)
+        || is_palette_color(value, replace_palette)
+        || theme_colors.iter().any(|color| color == value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_palette_colors() {
+        assert!(is_color("red-500"));
+        assert!(is_color("slate-950"));
+        assert!(is_color("mauve-50"));
+        assert!(is_color("current"));
+        assert!(!is_color("red-550"));
+        assert!(!is_color("red-1000"));
+        assert!(!is_color("crimson-500"));
+    }
+
+    #[test]
+    fn test_theme_colors() {
+        let theme_colors = vec!["ocean-breeze".to_string(), "ocean-breeze-500".to_string()];
+
+        assert!(is_color_in("ocean-breeze", &theme_colors, false));
+        assert!(is_color_in("ocean-breeze-500", &theme_colors, false));
+        assert!(is_color_in("red-500", &theme_colors, false));
+        assert!(!is_color_in("ocean-breeze-600", &theme_colors, false));
+        assert!(!is_color_in("ocean-breeze", &[], false));
+    }
+
+    #[test]
+    fn test_theme_colors_replace_palette() {
+        let theme_colors = vec!["brand".to_string()];
+
+        assert!(is_color_in("brand", &theme_colors, true));
+        assert!(is_color_in("current", &theme_colors, true));
+        assert!(!is_color_in("red-500", &theme_colors, true));
+    }
+}
@@ -0,0 +1,192 @@
+use similar::{ChangeTag, DiffOp, TextDiff};
+
+use crate::sort_document;
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const CYAN: &str = "\x1b[36m";
+const BOLD: &str = "\x1b[1m";
+const INVERSE: &str = "\x1b[7m";
+const RESET: &str = "\x1b[0m";
+
+/// Options controlling how a unified diff is rendered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffOptions {
+    /// Number of unchanged lines shown around each change
+    pub context_lines: usize,
+    /// Use ANSI colors for headers and changed lines
+    pub color: bool,
+    /// Highlight the individual classes that moved within a changed line (requires `color`)
+    pub highlight_words: bool,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        DiffOptions {
+            context_lines: 3,
+            color: false,
+            highlight_words: true,
+        }
+    }
+}
+
+/// Sort a document and render the changes as a unified diff.
+///
+/// Returns an empty string when the document is already sorted.
+pub fn diff_document(
+    document: &str,
+    file_extension: &str,
+    file_name: &str,
+    options: &DiffOptions,
+) -> String {
+    let sorted = sort_document(document, file_extension);
+    unified_diff(file_name, document, &sorted, options)
+}
+
+/// Render a unified diff between an original and a sorted document.
+///
+/// Only the lines containing rewritten class strings (plus context) end up in hunks.
+/// Returns an empty string when both documents are identical.
+pub fn unified_diff(
+    file_name: &str,
+    original: &str,
+    sorted: &str,
+    options: &DiffOptions,
+) -> String {
+    if original == sorted {
+        return String::new();
+    }
+
+    let diff = TextDiff::from_lines(original, sorted);
+    let paint = |style: &str, text: &str| {
+        if options.color {
+            format!("{}{}{}", style, text, RESET)
+        } else {
+            text.to_string()
+        }
+    };
+
+    let file_name = file_name.trim_start_matches('/');
+    let mut output = String::new();
+    output.push_str(&paint(BOLD, &format!("--- a/{}", file_name)));
+    output.push('\n');
+    output.push_str(&paint(BOLD, &format!("+++ b/{}", file_name)));
+    output.push('\n');
+
+    for group in diff.grouped_ops(options.context_lines) {
+        output.push_str(&paint(CYAN, &hunk_header(&group)));
+        output.push('\n');
+
+        for op in &group {
+            for change in diff.iter_inline_changes(op) {
+                let (sign, style) = match change.tag() {
+                    ChangeTag::Delete => ("-", RED),
+                    ChangeTag::Insert => ("+", GREEN),
+                    ChangeTag::Equal => (" ", ""),
+                };
+
+                let mut line = String::new();
+                for (emphasized, text) in change.iter_strings_lossy() {
+                    let text = text.trim_end_matches(['\n', '\r']);
+                    if emphasized && options.color && options.highlight_words {
+                        line.push_str(&format!("{}{}{}{}", INVERSE, text, RESET, style));
+                    } else {
+                        line.push_str(text);
+                    }
+                }
+
+                if style.is_empty() {
+                    output.push_str(&format!("{}{}", sign, line));
+                } else {
+                    output.push_str(&paint(style, &format!("{}{}", sign, line)));
+                }
+                output.push('\n');
+
+                if change.missing_newline() {
+                    output.push_str("\\ No newline at end of file\n");
+                }
+            }
+        }
+    }
+
+    output
+}
+
+/// Format the `@@ -a,b +c,d @@` header for a group of diff operations
+fn hunk_header(group: &[DiffOp]) -> String {
+    let (Some(first), Some(last)) = (group.first(), group.last()) else {
+        return String::new();
+    };
+
+    let old_start = first.old_range().start;
+    let old_len = last.old_range().end - old_start;
+    let new_start = first.new_range().start;
+    let new_len = last.new_range().end - new_start;
+
+    // Unified diffs use one-based line numbers, except for empty ranges
+    let position = |start: usize, len: usize| {
+        if len == 0 { start } else { start + 1 }
+    };
+
+    format!(
+        "@@ -{},{} +{},{} @@",
+        position(old_start, old_len),
+        old_len,
+        position(new_start, new_len),
+        new_len
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identical_documents_have_no_diff() {
+        let document = "const a = \"mt-2 flex p-4\";\n";
+        assert_eq!(
+            unified_diff("a.ts", document, document, &DiffOptions::default()),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_hunks_cover_only_changed_lines() {
+        let original =
+            "line 1\nline 2\nline 3\nline 4\nline 5\nconst a = \"p-4 flex mt-2\";\nline 7\n";
+        let sorted =
+            "line 1\nline 2\nline 3\nline 4\nline 5\nconst a = \"mt-2 flex p-4\";\nline 7\n";
+        let options = DiffOptions {
+            context_lines: 1,
+            ..DiffOptions::default()
+        };
+
+        assert_eq!(
+            unified_diff("src/a.ts", original, sorted, &options),
+            "--- a/src/a.ts\n+++ b/src/a.ts\n@@ -5,3 +5,3 @@\n line 5\n-const a = \"p-4 flex mt-2\";\n+const a = \"mt-2 flex p-4\";\n line 7\n"
+        );
+    }
+
+    #[test]
+    fn test_missing_newline_at_end_of_file() {
+        let diff = unified_diff(
+            "a.ts",
+            "x = \"p-4 flex mt-2\"",
+            "x = \"mt-2 flex p-4\"",
+            &DiffOptions::default(),
+        );
+        assert!(diff.ends_with("+x = \"mt-2 flex p-4\"\n\\ No newline at end of file\n"));
+    }
+
+    #[test]
+    fn test_color_highlights_moved_classes() {
+        let options = DiffOptions {
+            color: true,
+            ..DiffOptions::default()
+        };
+        let diff = unified_diff("a.ts", "x = \"p-4 mt-2\"\n", "x = \"mt-2 p-4\"\n", &options);
+
+        assert!(diff.contains(RED) && diff.contains(GREEN));
+        assert!(diff.contains(INVERSE));
+    }
+}
@@ -0,0 +1,225 @@
+use std::path::Path;
+
+use dprint_core::configuration::{
+    ConfigKeyMap, ConfigurationDiagnostic, GlobalConfiguration, get_unknown_property_diagnostics,
+    get_value,
+};
+use dprint_core::plugins::{
+    CheckConfigUpdatesMessage, ConfigChange, FileMatchingInfo, FormatError, FormatResult,
+    PluginInfo, PluginResolveConfigurationResult, SyncFormatRequest, SyncHostFormatRequest,
+    SyncPluginHandler,
+};
+use serde::Serialize;
+
+use crate::ranges::TextRange;
+use crate::sorter::{
+    OutputMode, set_normalize_whitespace, set_output_mode, set_print_width, set_remove_duplicates,
+};
+use crate::{sort_classes_in_ranges, sort_document};
+
+/// File extensions the plugin formats
+const FILE_EXTENSIONS: &[&str] = &["js", "jsx", "mjs", "cjs", "ts", "tsx", "mts", "cts"];
+
+/// Resolved plugin configuration (the `"tailwindSorter"` key in `dprint.json`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Configuration {
+    pub remove_duplicates: bool,
+    pub normalize_whitespace: bool,
+    /// Wrap class lists longer than this many columns (0 disables wrapping)
+    pub print_width: u32,
+    pub output_mode: String,
+}
+
+/// dprint plugin that sorts Tailwind classes in JavaScript and TypeScript files
+#[derive(Debug, Default)]
+pub struct TailwindSorterPlugin;
+
+impl TailwindSorterPlugin {
+    pub const fn new() -> Self {
+        TailwindSorterPlugin
+    }
+}
+
+/// Resolve a raw configuration map, reporting unknown keys and invalid values
+pub fn resolve_config(mut config: ConfigKeyMap) -> (Configuration, Vec<ConfigurationDiagnostic>) {
+    let mut diagnostics = Vec::new();
+
+    let remove_duplicates = get_value(&mut config, "removeDuplicates", true, &mut diagnostics);
+    let normalize_whitespace =
+        get_value(&mut config, "normalizeWhitespace", true, &mut diagnostics);
+    let print_width = get_value(&mut config, "printWidth", 0, &mut diagnostics);
+    let output_mode: String = get_value(
+        &mut config,
+        "outputMode",
+        "flat".to_string(),
+        &mut diagnostics,
+    );
+
+    let output_mode = if OutputMode::from_name(&output_mode).is_some() {
+        output_mode
+    } else {
+        diagnostics.push(ConfigurationDiagnostic {
+            property_name: "outputMode".to_string(),
+            message: format!(
+                "Unknown output mode \"{}\", expected flat, spaced, lines or variant-lines",
+                output_mode
+            ),
+        });
+        "flat".to_string()
+    };
+
+    diagnostics.extend(get_unknown_property_diagnostics(config));
+
+    (
+        Configuration {
+            remove_duplicates,
+            normalize_whitespace,
+            print_width,
+            output_mode,
+        },
+        diagnostics,
+    )
+}
+
+/// Sort the classes of a file, limited to `range` when given.
+///
+/// Returns `None` when nothing changed.
+pub fn format_text(
+    file_path: &Path,
+    text: &str,
+    range: Option<std::ops::Range<usize>>,
+    config: &Configuration,
+) -> Option<String> {
+    let file_extension = file_path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default();
+
+    set_remove_duplicates(config.remove_duplicates);
+    set_normalize_whitespace(config.normalize_whitespace);
+    set_print_width(config.print_width as usize);
+    set_output_mode(OutputMode::from_name(&config.output_mode).unwrap_or(OutputMode::Flat));
+
+    let sorted = match range {
+        Some(range) => {
+            let range = TextRange {
+                start: range.start,
+                end: range.end,
+            };
+            sort_classes_in_ranges(text, file_extension, &[range])
+        }
+        None => sort_document(text, file_extension),
+    };
+
+    (sorted != text).then_some(sorted)
+}
+
+impl SyncPluginHandler<Configuration> for TailwindSorterPlugin {
+    fn resolve_config(
+        &mut self,
+        config: ConfigKeyMap,
+        _global_config: &GlobalConfiguration,
+    ) -> PluginResolveConfigurationResult<Configuration> {
+        let (config, diagnostics) = resolve_config(config);
+
+        PluginResolveConfigurationResult {
+            file_matching: FileMatchingInfo {
+                file_extensions: FILE_EXTENSIONS.iter().map(|ext| ext.to_string()).collect(),
+                file_names: Vec::new(),
+                // Sorting classes runs alongside the file's usual formatter
+                additive: true,
+            },
+            diagnostics,
+            config,
+        }
+    }
+
+    fn plugin_info(&mut self) -> PluginInfo {
+        PluginInfo {
+            name: env!("CARGO_PKG_NAME").to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            config_key: "tailwindSorter".to_string(),
+            help_url: "https://github.com/Digital-Magistery-Software/tailwind-class-sorter"
+                .to_string(),
+            config_schema_url: String::new(),
+            update_url: None,
+        }
+    }
+
+    fn license_text(&mut self) -> String {
+        include_str!("../../LICENSE").to_string()
+    }
+
+    fn check_config_updates(
+        &self,
+        _message: CheckConfigUpdatesMessage,
+    ) -> Result<Vec<ConfigChange>, FormatError> {
+        Ok(Vec::new())
+    }
+
+    fn format(
+        &mut self,
+        request: SyncFormatRequest<Configuration>,
+        _format_with_host: impl FnMut(SyncHostFormatRequest) -> FormatResult,
+    ) -> FormatResult {
+        let text = String::from_utf8(request.file_bytes)?;
+
+        Ok(
+            format_text(request.file_path, &text, request.range, request.config)
+                .map(String::into_bytes),
+        )
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+mod plugin {
+    use super::{Configuration, TailwindSorterPlugin};
+    use dprint_core::generate_plugin_code;
+    use dprint_core::plugins::SyncPluginHandler;
+
+    generate_plugin_code!(TailwindSorterPlugin, TailwindSorterPlugin::new());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dprint_core::configuration::ConfigKeyValue;
+
+    #[test]
+    fn test_resolve_config_defaults_and_diagnostics() {
+        let mut config = ConfigKeyMap::new();
+        config.insert("printWidth".to_string(), ConfigKeyValue::Number(100));
+        config.insert(
+            "outputMode".to_string(),
+            ConfigKeyValue::String("columns".to_string()),
+        );
+        config.insert("unknown".to_string(), ConfigKeyValue::Bool(true));
+
+        let (config, diagnostics) = resolve_config(config);
+
+        assert!(config.remove_duplicates && config.normalize_whitespace);
+        assert_eq!(config.print_width, 100);
+        assert_eq!(config.output_mode, "flat");
+        let properties: Vec<&str> = diagnostics
+            .iter()
+            .map(|d| d.property_name.as_str())
+            .collect();
+        assert_eq!(properties, vec!["outputMode", "unknown"]);
+    }
+
+    #[test]
+    fn test_format_text() {
+        let (config, _) = resolve_config(ConfigKeyMap::new());
+        let path = Path::new("src/App.tsx");
+
+        assert_eq!(
+            format_text(path, "<div className=\"p-4 flex mt-2\" />", None, &config),
+            Some("<div className=\"mt-2 flex p-4\" />".to_string())
+        );
+        assert_eq!(
+            format_text(path, "<div className=\"mt-2 flex p-4\" />", None, &config),
+            None
+        );
+    }
+}
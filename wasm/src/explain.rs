@@ -0,0 +1,156 @@
+use serde::Serialize;
+
+use crate::sorter::{
+    ClassCategory, group_tailwind_classes, resolve_property_order, split_preserving_brackets,
+    split_variant, split_variants,
+};
+use crate::variants::{highest_variant, variant_order};
+
+/// Why a class landed where it did in the sorted output
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassExplanation {
+    pub class: String,
+    /// The bucket the class was sorted into, e.g. `base` or `state-variant`
+    pub category: &'static str,
+    /// The variant prefix including its colons (`hover:` or `md:hover:`), if any
+    pub variant: Option<String>,
+    /// Position of the highest variant in Tailwind's variant order, for variant classes
+    pub variant_order: Option<usize>,
+    /// The Tailwind prefix the utility matched, if any
+    pub prefix: Option<PrefixExplanation>,
+    /// The sort key used within a bucket
+    pub property_order: PropertyOrder,
+}
+
+/// The matched entry from the prefix table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrefixExplanation {
+    pub prefix: String,
+    pub order: usize,
+    pub value_types: Vec<&'static str>,
+    pub is_standalone: bool,
+}
+
+/// The `(order, sub-property, negative, value)` tuple classes are compared by
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyOrder {
+    pub order: usize,
+    pub sub_property: String,
+    pub is_negative: bool,
+    pub value: String,
+}
+
+/// Explain the sort position of every class in a class string, in sorted order.
+///
+/// Duplicates are kept so that each input class is explained.
+pub fn explain_classes(class_string: &str) -> Vec<ClassExplanation> {
+    let classes = split_preserving_brackets(class_string);
+
+    group_tailwind_classes(&classes)
+        .into_iter()
+        .flat_map(|(category, group)| {
+            group
+                .into_iter()
+                .map(move |class| explain_class(class, category))
+        })
+        .collect()
+}
+
+fn explain_class(class: &str, category: ClassCategory) -> ClassExplanation {
+    let (variant, variant_order, base) = match category {
+        ClassCategory::StateVariant | ClassCategory::ResponsiveVariant => {
+            let (variant, base) = split_variant(class);
+            let order = highest_variant(&split_variants(class).0).map(variant_order);
+            (Some(variant), order, base)
+        }
+        _ => (None, None, class),
+    };
+
+    let (prefix, (order, sub_property, is_negative, value)) = resolve_property_order(base);
+
+    ClassExplanation {
+        class: class.to_string(),
+        category: category.as_str(),
+        variant: variant.map(str::to_string),
+        variant_order,
+        prefix: prefix.map(|prefix| PrefixExplanation {
+            prefix: prefix.prefix.into_owned(),
+            order: prefix.order,
+            value_types: prefix.value_types.iter().map(|t| t.as_str()).collect(),
+            is_standalone: prefix.is_standalone,
+        }),
+        property_order: PropertyOrder {
+            order,
+            sub_property,
+            is_negative,
+            value,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explains_classes_in_sorted_order() {
+        let explanations = explain_classes("hover:bg-red-500 p-4 custom-card md:flex -mt-2");
+        let classes: Vec<&str> = explanations.iter().map(|e| e.class.as_str()).collect();
+        assert_eq!(
+            classes,
+            vec!["custom-card", "-mt-2", "p-4", "hover:bg-red-500", "md:flex"]
+        );
+
+        let custom = &explanations[0];
+        assert_eq!(custom.category, "custom");
+        assert!(custom.prefix.is_none());
+
+        let margin = &explanations[1];
+        assert_eq!(margin.category, "base");
+        assert_eq!(margin.prefix.as_ref().unwrap().prefix, "mt-");
+        assert!(margin.property_order.is_negative);
+        assert_eq!(margin.property_order.value, "2");
+
+        let hover = &explanations[3];
+        assert_eq!(hover.category, "state-variant");
+        assert_eq!(hover.variant.as_deref(), Some("hover:"));
+        assert_eq!(hover.variant_order, Some(variant_order("hover:")));
+        assert_eq!(hover.prefix.as_ref().unwrap().prefix, "bg-");
+
+        let responsive = &explanations[4];
+        assert_eq!(responsive.category, "responsive-variant");
+        assert_eq!(responsive.variant_order, Some(variant_order("md:")));
+        assert!(responsive.prefix.as_ref().unwrap().is_standalone);
+    }
+
+    #[test]
+    fn test_explains_stacked_variants() {
+        let explanations = explain_classes("dark:md:hover:bg-blue-500");
+        assert_eq!(explanations[0].category, "responsive-variant");
+        assert_eq!(explanations[0].variant.as_deref(), Some("dark:md:hover:"));
+        assert_eq!(explanations[0].variant_order, Some(variant_order("dark")));
+        assert_eq!(explanations[0].prefix.as_ref().unwrap().prefix, "bg-");
+    }
+
+    #[test]
+    fn test_explains_arbitrary_values() {
+        let explanations = explain_classes("[mask-type:luminance] bg-[#fff]");
+
+        let arbitrary = explanations
+            .iter()
+            .find(|e| e.class == "bg-[#fff]")
+            .unwrap();
+        let prefix = arbitrary.prefix.as_ref().unwrap();
+        assert_eq!(prefix.prefix, "bg-");
+        assert!(prefix.value_types.contains(&"arbitrary-value"));
+
+        let property = explanations
+            .iter()
+            .find(|e| e.class == "[mask-type:luminance]")
+            .unwrap();
+        assert!(property.prefix.is_none());
+    }
+}
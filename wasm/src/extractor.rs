@@ -1,12 +1,50 @@
 use oxc::allocator::Allocator;
-use oxc::ast::ast::{StringLiteral, TemplateLiteral};
-use oxc::ast::visit::Visit;
+use oxc::ast::Comment;
+use oxc::ast::ast::{
+    CallExpression, Expression, JSXAttribute, JSXAttributeName, Program, StringLiteral,
+    TaggedTemplateExpression, TemplateLiteral,
+};
+use oxc::ast::visit::{Visit, walk};
 use oxc::parser::Parser;
-use oxc::span::SourceType;
+use oxc::span::{GetSpan, SourceType};
+use std::collections::HashSet;
+use std::fmt;
 use std::path::Path;
+use std::sync::{Mutex, OnceLock};
 
-use crate::console_log;
 use crate::sorter::{is_arbitrary_class, is_tailwind_class};
+use crate::{debug_log, trace_log, warn_log};
+
+static CLASS_ATTRIBUTES: OnceLock<Mutex<Vec<String>>> = OnceLock::new();
+static CLASS_FUNCTIONS: OnceLock<Mutex<Vec<String>>> = OnceLock::new();
+
+/// Set extra JSX attributes whose string values are always treated as class lists
+/// (Prettier's `tailwindAttributes`)
+pub fn set_class_attributes(names: Vec<String>) {
+    let mutex = CLASS_ATTRIBUTES.get_or_init(|| Mutex::new(Vec::new()));
+    if let Ok(mut value) = mutex.lock() {
+        *value = names;
+    }
+}
+
+/// Set functions and template tags whose string arguments are always treated as class lists
+/// (Prettier's `tailwindFunctions`)
+pub fn set_class_functions(names: Vec<String>) {
+    let mutex = CLASS_FUNCTIONS.get_or_init(|| Mutex::new(Vec::new()));
+    if let Ok(mut value) = mutex.lock() {
+        *value = names;
+    }
+}
+
+fn get_class_attributes() -> Vec<String> {
+    let mutex = CLASS_ATTRIBUTES.get_or_init(|| Mutex::new(Vec::new()));
+    mutex.lock().map(|guard| guard.clone()).unwrap_or_default()
+}
+
+fn get_class_functions() -> Vec<String> {
+    let mutex = CLASS_FUNCTIONS.get_or_init(|| Mutex::new(Vec::new()));
+    mutex.lock().map(|guard| guard.clone()).unwrap_or_default()
+}
 
 /// Represents a class string match in the document
 #[derive(Debug, Clone)]
@@ -23,49 +61,293 @@ pub struct ClassMatch {
     pub path: String,
 }
 
+/// Why the class strings of a document could not be extracted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExtractError {
+    /// The extension names no JavaScript or TypeScript dialect
+    UnsupportedExtension(String),
+    /// The document has syntax errors, with the parser's messages
+    Parse(Vec<String>),
+}
+
+impl fmt::Display for ExtractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExtractError::UnsupportedExtension(extension) => {
+                write!(f, "unsupported file extension \"{}\"", extension)
+            }
+            ExtractError::Parse(messages) => match messages.first() {
+                Some(message) => write!(f, "parse error: {}", message),
+                None => write!(f, "parse error"),
+            },
+        }
+    }
+}
+
+impl std::error::Error for ExtractError {}
+
+/// Extract class strings for sorting, treating documents that can't be parsed as having none
 pub fn extract_class_strings(document: &str, file_extension: &str) -> Vec<ClassMatch> {
+    try_extract_class_strings(document, file_extension).unwrap_or_else(|error| {
+        warn_log!("Not sorting document: {}", error);
+        Vec::new()
+    })
+}
+
+/// Extract class strings, failing when the document can't be parsed
+pub fn try_extract_class_strings(
+    document: &str,
+    file_extension: &str,
+) -> Result<Vec<ClassMatch>, ExtractError> {
+    extract_class_strings_with(
+        document,
+        file_extension,
+        get_class_attributes(),
+        get_class_functions(),
+    )
+}
+
+/// Extract class strings, always treating the given attributes and functions as class lists
+fn extract_class_strings_with(
+    document: &str,
+    file_extension: &str,
+    class_attributes: Vec<String>,
+    class_functions: Vec<String>,
+) -> Result<Vec<ClassMatch>, ExtractError> {
     let allocator = Allocator::default();
 
     // Create a fake path for source type detection
     let fake_path = format!("test.{}", file_extension);
-    let source_type = SourceType::from_path(Path::new(&fake_path)).unwrap_or_default();
+    let source_type = SourceType::from_path(Path::new(&fake_path))
+        .map_err(|_| ExtractError::UnsupportedExtension(file_extension.to_string()))?;
 
     let parser = Parser::new(&allocator, document, source_type);
     let ret = parser.parse();
 
     if !ret.errors.is_empty() {
-        console_log!("Parsing errors: {:?}", ret.errors);
-        return vec![];
+        return Err(ExtractError::Parse(
+            ret.errors.iter().map(|error| error.to_string()).collect(),
+        ));
+    }
+
+    let directives = IgnoreDirectives::from_program(&ret.program, document);
+
+    if directives.ignore_file {
+        debug_log!("Skipping file with tailwind-sort-ignore-file directive");
+        return Ok(Vec::new());
     }
 
     let mut visitor = TailwindClassVisitor {
         document,
         class_matches: Vec::new(),
+        ignored_node_starts: directives.ignored_node_starts,
+        class_attributes,
+        class_functions,
+        in_class_context: false,
     };
 
     visitor.visit_program(&ret.program);
 
-    console_log!(
+    // Drop matches starting on a line covered by tailwind-sort-ignore-next-line
+    if !directives.ignored_lines.is_empty() {
+        visitor.class_matches.retain(|m| {
+            !directives
+                .ignored_lines
+                .contains(&line_of(document, m.start))
+        });
+    }
+
+    debug_log!(
         "Found {} Tailwind class matches",
         visitor.class_matches.len()
     );
 
     // Sort by position from end to start to avoid offset issues when replacing
-    visitor.class_matches.sort_by(|a, b| b.start.cmp(&a.start));
+    visitor
+        .class_matches
+        .sort_by_key(|m| std::cmp::Reverse(m.start));
+
+    Ok(visitor.class_matches)
+}
+
+/// A comment directive that stops the sorter from touching class strings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IgnoreDirective {
+    /// `tailwind-sort-ignore-file`: skip the whole file
+    File,
+    /// `tailwind-sort-ignore-next-line`: skip class strings starting on the next line
+    NextLine,
+    /// `tailwind-sort-ignore`: skip the JSX attribute, call or literal that follows
+    Node,
+}
+
+impl IgnoreDirective {
+    /// Parse a directive from a comment's text (including its delimiters)
+    fn parse(comment_text: &str) -> Option<IgnoreDirective> {
+        let content = comment_text
+            .trim_start_matches("//")
+            .trim_start_matches("/*")
+            .trim_end_matches("*/")
+            .trim_start_matches(['*', ' ', '\t', '\n', '\r']);
+
+        match content.split_whitespace().next()? {
+            "tailwind-sort-ignore-file" => Some(IgnoreDirective::File),
+            "tailwind-sort-ignore-next-line" => Some(IgnoreDirective::NextLine),
+            "tailwind-sort-ignore" => Some(IgnoreDirective::Node),
+            _ => None,
+        }
+    }
+}
+
+/// Ignore directives collected from a program's comments
+#[derive(Debug, Default)]
+struct IgnoreDirectives {
+    ignore_file: bool,
+    /// Zero-based lines whose class strings must be left alone
+    ignored_lines: HashSet<usize>,
+    /// Start offsets of the nodes that `tailwind-sort-ignore` comments are attached to
+    ignored_node_starts: HashSet<u32>,
+}
+
+impl IgnoreDirectives {
+    fn from_program(program: &Program, document: &str) -> Self {
+        let mut directives = IgnoreDirectives::default();
+
+        // File directives only count before the first line of code
+        let first_code_start = program
+            .directives
+            .first()
+            .map(|d| d.span.start)
+            .into_iter()
+            .chain(program.body.first().map(|s| s.span().start))
+            .min()
+            .unwrap_or(u32::MAX);
+
+        for comment in program.comments.iter() {
+            let Some(directive) = comment_directive(comment, document) else {
+                continue;
+            };
+
+            match directive {
+                IgnoreDirective::File => {
+                    if comment.span.start <= first_code_start {
+                        directives.ignore_file = true;
+                    }
+                }
+                IgnoreDirective::NextLine => {
+                    directives
+                        .ignored_lines
+                        .insert(line_of(document, comment.span.end as usize) + 1);
+                }
+                IgnoreDirective::Node => {
+                    if comment.is_leading() {
+                        directives.ignored_node_starts.insert(comment.attached_to);
+                    }
+                }
+            }
+        }
+
+        directives
+    }
+}
 
-    visitor.class_matches
+fn comment_directive(comment: &Comment, document: &str) -> Option<IgnoreDirective> {
+    let start = comment.span.start as usize;
+    let end = comment.span.end as usize;
+    document.get(start..end).and_then(IgnoreDirective::parse)
+}
+
+/// Get the zero-based line number of a byte offset
+fn line_of(document: &str, offset: usize) -> usize {
+    document.as_bytes()[..offset.min(document.len())]
+        .iter()
+        .filter(|&&b| b == b'\n')
+        .count()
 }
 
 struct TailwindClassVisitor<'a> {
     document: &'a str,
     class_matches: Vec<ClassMatch>,
+    ignored_node_starts: HashSet<u32>,
+    /// Configured attribute names whose values are always class lists
+    class_attributes: Vec<String>,
+    /// Configured function and tag names whose arguments are always class lists
+    class_functions: Vec<String>,
+    /// Whether literals are inside a configured attribute or function call
+    in_class_context: bool,
+}
+
+impl TailwindClassVisitor<'_> {
+    fn is_class_function(&self, callee: &Expression) -> bool {
+        callee_name(callee).is_some_and(|name| self.class_functions.iter().any(|f| f == name))
+    }
+
+    /// Check a literal value against the heuristic, or just for content in a class context
+    fn is_class_string(&self, value: &str) -> bool {
+        if self.in_class_context {
+            !value.trim().is_empty()
+        } else {
+            looks_like_class_string(value)
+        }
+    }
+}
+
+/// Get the name a call or tag is matched by: `cn` for `cn(...)`, `tw` for `` tw.div`...` ``
+fn callee_name<'a>(callee: &'a Expression) -> Option<&'a str> {
+    match callee {
+        Expression::Identifier(ident) => Some(ident.name.as_str()),
+        Expression::StaticMemberExpression(member) => callee_name(&member.object),
+        _ => None,
+    }
 }
 
 impl<'a> Visit<'a> for TailwindClassVisitor<'a> {
+    fn visit_jsx_attribute(&mut self, attribute: &JSXAttribute<'a>) {
+        if self.ignored_node_starts.contains(&attribute.span.start) {
+            debug_log!("Skipping JSX attribute with tailwind-sort-ignore directive");
+            return;
+        }
+
+        let previous = self.in_class_context;
+        self.in_class_context = match &attribute.name {
+            JSXAttributeName::Identifier(ident) => self
+                .class_attributes
+                .iter()
+                .any(|name| name == ident.name.as_str()),
+            JSXAttributeName::NamespacedName(_) => false,
+        };
+        walk::walk_jsx_attribute(self, attribute);
+        self.in_class_context = previous;
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if self.ignored_node_starts.contains(&call.span.start) {
+            debug_log!("Skipping call expression with tailwind-sort-ignore directive");
+            return;
+        }
+
+        // Arguments of other calls nested in a class context are not class lists themselves
+        let previous = self.in_class_context;
+        self.in_class_context = self.is_class_function(&call.callee);
+        walk::walk_call_expression(self, call);
+        self.in_class_context = previous;
+    }
+
+    fn visit_tagged_template_expression(&mut self, tagged: &TaggedTemplateExpression<'a>) {
+        let previous = self.in_class_context;
+        self.in_class_context = self.is_class_function(&tagged.tag);
+        walk::walk_tagged_template_expression(self, tagged);
+        self.in_class_context = previous;
+    }
+
     fn visit_string_literal(&mut self, string_lit: &StringLiteral<'a>) {
+        if self.ignored_node_starts.contains(&string_lit.span.start) {
+            return;
+        }
+
         let value = string_lit.value.as_str();
 
-        if looks_like_class_string(value) {
+        if self.is_class_string(value) {
             let start = string_lit.span.start as usize;
             let end = string_lit.span.end as usize;
 
@@ -85,18 +367,24 @@ impl<'a> Visit<'a> for TailwindClassVisitor<'a> {
                 path: "string_literal".to_string(),
             });
 
-            console_log!("Found Tailwind classes in string literal: {}", value);
+            trace_log!("Found Tailwind classes in string literal: {}", value);
         }
     }
 
     fn visit_template_literal(&mut self, template_lit: &TemplateLiteral<'a>) {
+        if self.ignored_node_starts.contains(&template_lit.span.start) {
+            return;
+        }
+
         // Check if this template literal might contain Tailwind classes
         let mut has_tailwind_classes = false;
 
         for quasi in template_lit.quasis.iter() {
             if let Some(cooked) = &quasi.value.cooked {
                 let value = cooked.as_str();
-                if value.split_whitespace().any(is_tailwind_class) {
+                if value.split_whitespace().any(is_tailwind_class)
+                    || (self.in_class_context && !value.trim().is_empty())
+                {
                     has_tailwind_classes = true;
                     break;
                 }
@@ -132,7 +420,7 @@ impl<'a> Visit<'a> for TailwindClassVisitor<'a> {
                     path: "template_literal_for_sorter".to_string(),
                 });
 
-                console_log!(
+                trace_log!(
                     "Found Tailwind classes in template literal: {}",
                     inner_content
                 );
@@ -213,3 +501,99 @@ fn looks_like_class_string(value: &str) -> bool {
         && !has_javascript_operators
         && !has_semicolon
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extracted(document: &str) -> Vec<String> {
+        let mut matches = extract_class_strings(document, "tsx");
+        matches.sort_by_key(|m| m.start);
+        matches.into_iter().map(|m| m.class_string).collect()
+    }
+
+    #[test]
+    fn test_extracts_jsx_attribute() {
+        let document = r#"const a = <div className="p-4 flex mt-2">Test</div>;"#;
+        assert_eq!(extracted(document), vec!["p-4 flex mt-2"]);
+    }
+
+    #[test]
+    fn test_ignore_file_directive() {
+        let document = r#"// tailwind-sort-ignore-file
+const a = <div className="p-4 flex mt-2">Test</div>;"#;
+        assert!(extracted(document).is_empty());
+    }
+
+    #[test]
+    fn test_ignore_file_directive_after_code_is_ignored() {
+        let document = r#"const a = <div className="p-4 flex mt-2">Test</div>;
+// tailwind-sort-ignore-file"#;
+        assert_eq!(extracted(document), vec!["p-4 flex mt-2"]);
+    }
+
+    #[test]
+    fn test_ignore_next_line_directive() {
+        let document = r#"// tailwind-sort-ignore-next-line
+const a = "p-4 flex mt-2";
+const b = "p-4 flex mt-2 block";"#;
+        assert_eq!(extracted(document), vec!["p-4 flex mt-2 block"]);
+    }
+
+    #[test]
+    fn test_ignore_directive_before_jsx_attribute() {
+        let document = r#"const a = (
+  <div
+    /* tailwind-sort-ignore */
+    className="p-4 flex mt-2"
+    title="block p-2 m-4"
+  />
+);"#;
+        assert_eq!(extracted(document), vec!["block p-2 m-4"]);
+    }
+
+    #[test]
+    fn test_ignore_directive_before_call() {
+        let document = r#"const a = /* tailwind-sort-ignore */ cn("p-4 flex mt-2", "m-4 block p-2");
+const b = cn("p-4 flex mt-2");"#;
+        assert_eq!(extracted(document), vec!["p-4 flex mt-2"]);
+    }
+
+    #[test]
+    fn test_configured_attributes_and_functions() {
+        let document = r#"const a = <Button tw="card header" title="card header" />;
+const b = styles("card header");
+const c = other(styles("card header"));
+const d = css.div`card header`;"#;
+
+        let mut matches = extract_class_strings_with(
+            document,
+            "tsx",
+            vec!["tw".to_string()],
+            vec!["styles".to_string(), "css".to_string()],
+        )
+        .unwrap();
+        matches.sort_by_key(|m| m.start);
+        let configured: Vec<String> = matches.into_iter().map(|m| m.class_string).collect();
+
+        assert_eq!(
+            configured,
+            vec!["card header", "card header", "card header", "card header"]
+        );
+        let unconfigured =
+            extract_class_strings_with(document, "tsx", Vec::new(), Vec::new()).unwrap();
+        assert!(unconfigured.is_empty());
+    }
+
+    #[test]
+    fn test_extract_errors() {
+        let broken = try_extract_class_strings("const a = <div className=\"p-4\"", "tsx");
+        assert!(matches!(broken, Err(ExtractError::Parse(messages)) if !messages.is_empty()));
+
+        assert_eq!(
+            try_extract_class_strings("const a = 1;", "txt").unwrap_err(),
+            ExtractError::UnsupportedExtension("txt".to_string())
+        );
+        assert!(extract_class_strings("const a = <div", "tsx").is_empty());
+    }
+}
@@ -0,0 +1,249 @@
+use crate::extractor::ClassMatch;
+use crate::sorter::{
+    ClassCategory, OutputMode, get_output_mode, get_print_width, is_normalize_whitespace_enabled,
+    sort_classes, sort_classes_grouped, split_variant,
+};
+
+/// Check whether the literal a class string came from may span multiple lines.
+///
+/// Only template literals can contain raw newlines, JS string literals cannot.
+pub fn permits_newlines(original: &str) -> bool {
+    original.len() >= 2 && original.starts_with('`') && original.ends_with('`')
+}
+
+/// Get the column of a byte offset and the indentation of the line containing it
+pub fn line_position(document: &str, offset: usize) -> (usize, &str) {
+    let offset = offset.min(document.len());
+    let line_start = document[..offset].rfind('\n').map_or(0, |pos| pos + 1);
+    let line = &document[line_start..];
+    let indent_len = line.len() - line.trim_start_matches([' ', '\t']).len();
+
+    (
+        document[line_start..offset].chars().count(),
+        &line[..indent_len],
+    )
+}
+
+/// Get the sorted class string for a match, laid out the way it will be written back
+pub fn sort_class_match(document: &str, class_match: &ClassMatch) -> String {
+    // Apply grouped output and wrapping, using line breaks only where the literal allows them
+    let (column, indent) = line_position(document, class_match.start + 1);
+    let allows_newlines = permits_newlines(&class_match.original);
+
+    sort_and_layout_classes(&class_match.class_string, column, indent, allows_newlines)
+}
+
+/// Sort a class string and lay it out according to the output mode and print width.
+///
+/// `start_column` is the column of the first class, `indent` the indentation of the
+/// line the literal starts on. Line-based output modes fall back to `Spaced` when the
+/// literal cannot contain newlines. Strings the grouped sorter leaves alone, and all
+/// strings when whitespace is preserved, get the plain `sort_classes` output.
+pub fn sort_and_layout_classes(
+    class_string: &str,
+    start_column: usize,
+    indent: &str,
+    allows_newlines: bool,
+) -> String {
+    // Custom layouts only make sense when we control all whitespace in the output
+    if !is_normalize_whitespace_enabled() {
+        return sort_classes(class_string);
+    }
+
+    let Some(groups) = sort_classes_grouped(class_string) else {
+        return sort_classes(class_string);
+    };
+
+    let mode = get_output_mode();
+    let print_width = get_print_width().filter(|_| allows_newlines);
+    let continuation_indent = format!("{}  ", indent);
+
+    match mode {
+        OutputMode::Lines if allows_newlines => {
+            let groups: Vec<Vec<&str>> = groups.into_iter().map(|(_, group)| group).collect();
+            join_lines(&groups, &continuation_indent)
+        }
+        OutputMode::VariantLines if allows_newlines => {
+            join_lines(&split_by_variant_prefix(groups), &continuation_indent)
+        }
+        _ => {
+            let separator = if mode == OutputMode::Flat { " " } else { "  " };
+            let groups: Vec<Vec<&str>> = groups.into_iter().map(|(_, group)| group).collect();
+
+            match print_width {
+                Some(width) => wrap_class_groups(
+                    &groups,
+                    start_column,
+                    &continuation_indent,
+                    width,
+                    separator,
+                ),
+                None => groups
+                    .iter()
+                    .map(|group| group.join(" "))
+                    .collect::<Vec<_>>()
+                    .join(separator),
+            }
+        }
+    }
+}
+
+/// Split variant categories into one group per variant prefix
+fn split_by_variant_prefix<'a>(groups: Vec<(ClassCategory, Vec<&'a str>)>) -> Vec<Vec<&'a str>> {
+    let mut result: Vec<Vec<&'a str>> = Vec::new();
+
+    for (category, group) in groups {
+        if !matches!(
+            category,
+            ClassCategory::StateVariant | ClassCategory::ResponsiveVariant
+        ) {
+            result.push(group);
+            continue;
+        }
+
+        // Variant groups are already sorted, so classes sharing a prefix are adjacent
+        let mut current_prefix = None;
+        for class in group {
+            let (prefix, _) = split_variant(class);
+            if current_prefix != Some(prefix) {
+                result.push(Vec::new());
+                current_prefix = Some(prefix);
+            }
+            if let Some(line) = result.last_mut() {
+                line.push(class);
+            }
+        }
+    }
+
+    result
+}
+
+/// Put each group on its own line
+fn join_lines(groups: &[Vec<&str>], indent: &str) -> String {
+    groups
+        .iter()
+        .filter(|group| !group.is_empty())
+        .map(|group| group.join(" "))
+        .collect::<Vec<_>>()
+        .join(&format!("\n{}", indent))
+}
+
+/// Lay out sorted class groups, only breaking lines between groups.
+///
+/// Groups are packed greedily: a group moves to a new line when adding it would push
+/// the current line (plus the closing quote) past `print_width`. A single group that is
+/// wider than the limit stays on one line. Groups sharing a line are joined by `separator`.
+pub fn wrap_class_groups(
+    groups: &[Vec<&str>],
+    start_column: usize,
+    indent: &str,
+    print_width: usize,
+    separator: &str,
+) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut current_column = start_column;
+
+    for group in groups.iter().filter(|group| !group.is_empty()) {
+        let joined = group.join(" ");
+        let separator_width = if current.is_empty() {
+            0
+        } else {
+            separator.chars().count()
+        };
+        let line_width = current_column
+            + current.chars().count()
+            + separator_width
+            + joined.chars().count()
+            // Leave room for the closing quote
+            + 1;
+
+        if !current.is_empty() && line_width > print_width {
+            lines.push(std::mem::take(&mut current));
+            current_column = indent.chars().count();
+        }
+
+        if !current.is_empty() {
+            current.push_str(separator);
+        }
+        current.push_str(&joined);
+    }
+
+    if !current.is_empty() {
+        lines.push(current);
+    }
+
+    lines.join(&format!("\n{}", indent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_fits_on_one_line() {
+        let groups = vec![vec!["flex", "p-4"], vec!["hover:bg-red-500"]];
+        assert_eq!(
+            wrap_class_groups(&groups, 10, "  ", 80, " "),
+            "flex p-4 hover:bg-red-500"
+        );
+    }
+
+    #[test]
+    fn test_wrap_breaks_at_group_boundaries() {
+        let groups = vec![
+            vec!["flex", "items-center", "bg-white", "p-4"],
+            vec!["hover:bg-gray-100", "focus:outline-none"],
+            vec!["sm:p-6", "md:p-8"],
+        ];
+        assert_eq!(
+            wrap_class_groups(&groups, 20, "    ", 60, " "),
+            "flex items-center bg-white p-4\n    hover:bg-gray-100 focus:outline-none sm:p-6 md:p-8"
+        );
+    }
+
+    #[test]
+    fn test_wrap_keeps_oversized_group_intact() {
+        let groups = vec![
+            vec!["flex", "items-center", "justify-between"],
+            vec!["md:p-8"],
+        ];
+        assert_eq!(
+            wrap_class_groups(&groups, 0, "  ", 10, " "),
+            "flex items-center justify-between\n  md:p-8"
+        );
+    }
+
+    #[test]
+    fn test_split_by_variant_prefix() {
+        let groups = vec![
+            (ClassCategory::Base, vec!["flex", "p-4"]),
+            (
+                ClassCategory::StateVariant,
+                vec!["hover:bg-gray-100", "hover:text-black", "focus:ring-2"],
+            ),
+            (
+                ClassCategory::ResponsiveVariant,
+                vec!["sm:p-6", "md:flex", "md:p-8"],
+            ),
+        ];
+        assert_eq!(
+            join_lines(&split_by_variant_prefix(groups), "  "),
+            "flex p-4\n  hover:bg-gray-100 hover:text-black\n  focus:ring-2\n  sm:p-6\n  md:flex md:p-8"
+        );
+    }
+
+    #[test]
+    fn test_permits_newlines() {
+        assert!(permits_newlines("`flex p-4`"));
+        assert!(!permits_newlines("\"flex p-4\""));
+        assert!(!permits_newlines("'flex p-4'"));
+    }
+
+    #[test]
+    fn test_line_position() {
+        let document = "const a = 1;\n    <div className={`flex p-4`} />";
+        let offset = document.find('`').unwrap() + 1;
+        assert_eq!(line_position(document, offset), (21, "    "));
+    }
+}
@@ -1,75 +1,122 @@
-use wasm_bindgen::prelude::*;
-
+pub mod check;
+pub mod colors;
+pub mod diff;
+#[cfg(feature = "dprint")]
+pub mod dprint;
+pub mod explain;
 pub mod extractor;
+pub mod layout;
+pub mod logging;
+pub mod lsp;
 mod prefixes;
+pub mod ranges;
+pub mod report;
 pub mod sorter;
+pub mod tailwind_config;
+pub mod theme;
 pub mod utils;
+pub mod variants;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use extractor::ClassMatch;
+pub use logging::{Logger, NoopLogger, set_logger};
 
-use extractor::extract_class_strings;
-use sorter::{
-    is_debug_enabled, set_debug_mode, set_normalize_whitespace, set_remove_duplicates, sort_classes,
-};
+use extractor::{ExtractError, extract_class_strings, try_extract_class_strings};
+use layout::sort_class_match;
+use ranges::{TextEdit, TextRange};
 
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = console)]
-    pub fn log(s: &str);
+/// Find the class strings in a document, ordered from the end of the document to the start
+pub fn extract(document: &str, file_extension: &str) -> Vec<ClassMatch> {
+    extract_class_strings(document, file_extension)
 }
 
-#[macro_export]
-macro_rules! console_log {
-    ($($t:tt)*) => {
-        $crate::log(&format!($($t)*))
-    }
+/// Sort a single class string
+pub fn sort_classes(class_string: &str) -> String {
+    sorter::sort_classes(class_string)
 }
 
-#[macro_export]
-macro_rules! debug_log {
-    ($($t:tt)*) => {
-        if is_debug_enabled() {
-            $crate::log(&format!("[DEBUG] {}", format!($($t)*)))
-        }
-    }
+/// Sort every class string in a document
+pub fn sort_document(document: &str, file_extension: &str) -> String {
+    info_log!("Starting Tailwind class sorting for {}", file_extension);
+
+    let class_matches = extract_class_strings(document, file_extension);
+
+    apply_edits(document, &class_match_edits(document, &class_matches))
 }
 
-#[wasm_bindgen]
-pub fn configure_tailwind_sorter(
-    remove_duplicates: bool,
-    debug_mode: bool,
-    normalize_whitespace: bool,
-) {
-    console_log!(
-        "Configuring Tailwind sorter with remove_duplicates={}, debug_mode={}, normalize_whitespace={}",
-        remove_duplicates,
-        debug_mode,
-        normalize_whitespace
+/// Sort every class string in a document, failing when the document can't be parsed
+pub fn try_sort_document(document: &str, file_extension: &str) -> Result<String, ExtractError> {
+    info_log!("Starting Tailwind class sorting for {}", file_extension);
+
+    let class_matches = try_extract_class_strings(document, file_extension)?;
+
+    Ok(apply_edits(
+        document,
+        &class_match_edits(document, &class_matches),
+    ))
+}
+
+/// Sort only the class strings that intersect any of the given byte ranges
+pub fn sort_classes_in_ranges(
+    document: &str,
+    file_extension: &str,
+    ranges: &[TextRange],
+) -> String {
+    info_log!(
+        "Starting Tailwind class sorting for {} in {} ranges",
+        file_extension,
+        ranges.len()
     );
-    set_remove_duplicates(remove_duplicates);
-    set_debug_mode(debug_mode);
-    set_normalize_whitespace(normalize_whitespace);
+
+    apply_edits(
+        document,
+        &class_edits_in_ranges(document, file_extension, ranges),
+    )
+}
+
+/// Get one edit per class string intersecting any of the given byte ranges that sorting
+/// would rewrite, ordered from end to start
+pub fn class_edits_in_ranges(
+    document: &str,
+    file_extension: &str,
+    ranges: &[TextRange],
+) -> Vec<TextEdit> {
+    let class_matches: Vec<ClassMatch> = extract_class_strings(document, file_extension)
+        .into_iter()
+        .filter(|m| ranges.iter().any(|r| r.intersects(m.start, m.end)))
+        .collect();
+
+    class_match_edits(document, &class_matches)
 }
 
-#[wasm_bindgen]
-pub fn sort_tailwind_classes(document: &str, file_extension: &str) -> String {
-    console_log!("Starting Tailwind class sorting for {}", file_extension);
+/// Apply edits ordered from end to start, so earlier offsets stay valid
+fn apply_edits(document: &str, edits: &[TextEdit]) -> String {
+    let mut result = document.to_string();
+    for edit in edits {
+        result.replace_range(edit.start..edit.end, &edit.replacement);
+    }
 
-    let class_matches = extract_class_strings(document, file_extension);
+    info_log!("Completed sorting: {} class strings modified", edits.len());
+
+    result
+}
 
-    // If no matches found, return the original document
+/// Get the edits that replace class matches (ordered from end to start) with their sorted
+/// classes, skipping matches that are already sorted
+fn class_match_edits(document: &str, class_matches: &[ClassMatch]) -> Vec<TextEdit> {
+    // If no matches found, there is nothing to rewrite
     if class_matches.is_empty() {
-        console_log!("No Tailwind classes found to sort");
-        return document.to_string();
+        debug_log!("No Tailwind classes found to sort");
+        return Vec::new();
     }
 
     debug_log!("Found {} class matches to process", class_matches.len());
 
-    let mut result = document.to_string();
-    let mut replaced_count = 0;
-
-    // Replace each class string with its sorted version
-    // Process from end to start to avoid offset issues
-    for class_match in &class_matches {
-        let sorted_classes = sort_classes(&class_match.class_string);
+    let mut edits = Vec::new();
+    // Keep the end-to-start order so the edits can be applied one after another
+    for class_match in class_matches {
+        let sorted_classes = sort_class_match(document, class_match);
 
         // Only replace if the order changed
         if sorted_classes != class_match.class_string {
@@ -99,19 +146,20 @@ pub fn sort_tailwind_classes(document: &str, file_extension: &str) -> String {
             let start = class_match.start;
             let end = class_match.end;
 
-            if start < result.len() && end <= result.len() {
-                // Replace the original with the sorted version
-                result.replace_range(start..end, &replacement);
-                replaced_count += 1;
-
+            if start < document.len() && end <= document.len() {
                 debug_log!(
                     "Sorted: \"{}\" → \"{}\"",
                     class_match.class_string,
                     sorted_classes
                 );
+                edits.push(TextEdit {
+                    start,
+                    end,
+                    replacement,
+                });
             } else {
-                console_log!(
-                    "Warning: Could not replace class string at positions {}-{} (out of bounds)",
+                warn_log!(
+                    "Could not replace class string at positions {}-{} (out of bounds)",
                     start,
                     end
                 );
@@ -124,10 +172,5 @@ pub fn sort_tailwind_classes(document: &str, file_extension: &str) -> String {
         }
     }
 
-    console_log!(
-        "Completed sorting: {} class strings modified",
-        replaced_count
-    );
-
-    result
+    edits
 }
@@ -0,0 +1,201 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{OnceLock, RwLock};
+
+/// Severity of a log message, from most to least severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Error = 1,
+    Warn = 2,
+    Info = 3,
+    Debug = 4,
+    Trace = 5,
+}
+
+impl LogLevel {
+    /// Parse a level name, as passed in from the extension settings
+    pub fn from_name(name: &str) -> Option<LogLevel> {
+        match name {
+            "error" => Some(LogLevel::Error),
+            "warn" => Some(LogLevel::Warn),
+            "info" => Some(LogLevel::Info),
+            "debug" => Some(LogLevel::Debug),
+            "trace" => Some(LogLevel::Trace),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }
+    }
+}
+
+/// A sink for the sorter's log messages.
+///
+/// The core never writes to stdout or the console itself: messages go to the installed
+/// logger, which is a no-op by default (and the browser console in wasm builds).
+pub trait Logger: Send + Sync {
+    fn log(&self, level: LogLevel, message: &str);
+}
+
+/// Discards every message
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopLogger;
+
+impl Logger for NoopLogger {
+    fn log(&self, _level: LogLevel, _message: &str) {}
+}
+
+static LOGGER: OnceLock<RwLock<Box<dyn Logger>>> = OnceLock::new();
+
+/// Most verbose level that is passed on to the logger (`0` turns logging off)
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(LogLevel::Info as u8);
+
+fn default_logger() -> Box<dyn Logger> {
+    #[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+    {
+        Box::new(crate::wasm::ConsoleLogger)
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", feature = "wasm")))]
+    {
+        Box::new(NoopLogger)
+    }
+}
+
+/// Install the logger that receives all messages from now on
+pub fn set_logger(logger: Box<dyn Logger>) {
+    let lock = LOGGER.get_or_init(|| RwLock::new(default_logger()));
+    if let Ok(mut value) = lock.write() {
+        *value = logger;
+    }
+}
+
+/// Set the most verbose level that is logged (`None` disables logging)
+pub fn set_max_level(level: Option<LogLevel>) {
+    MAX_LEVEL.store(level.map_or(0, |level| level as u8), Ordering::Relaxed);
+}
+
+/// Check whether messages at `level` are logged.
+///
+/// The logging macros check this before formatting their message.
+pub fn enabled(level: LogLevel) -> bool {
+    passes(level, MAX_LEVEL.load(Ordering::Relaxed))
+}
+
+/// Check whether `level` is at most as verbose as `max_level` (`0` passes nothing)
+fn passes(level: LogLevel, max_level: u8) -> bool {
+    level as u8 <= max_level
+}
+
+/// Send a message to the installed logger
+pub fn log(level: LogLevel, message: &str) {
+    let lock = LOGGER.get_or_init(|| RwLock::new(default_logger()));
+    if let Ok(logger) = lock.read() {
+        log_to(
+            logger.as_ref(),
+            MAX_LEVEL.load(Ordering::Relaxed),
+            level,
+            message,
+        );
+    }
+}
+
+/// Send a message to `logger` if its level passes `max_level`
+fn log_to(logger: &dyn Logger, max_level: u8, level: LogLevel, message: &str) {
+    if passes(level, max_level) {
+        logger.log(level, message);
+    }
+}
+
+#[macro_export]
+macro_rules! log_at {
+    ($level:expr, $($t:tt)*) => {
+        if $crate::logging::enabled($level) {
+            $crate::logging::log($level, &format!($($t)*))
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! error_log {
+    ($($t:tt)*) => { $crate::log_at!($crate::logging::LogLevel::Error, $($t)*) }
+}
+
+#[macro_export]
+macro_rules! warn_log {
+    ($($t:tt)*) => { $crate::log_at!($crate::logging::LogLevel::Warn, $($t)*) }
+}
+
+#[macro_export]
+macro_rules! info_log {
+    ($($t:tt)*) => { $crate::log_at!($crate::logging::LogLevel::Info, $($t)*) }
+}
+
+#[macro_export]
+macro_rules! debug_log {
+    ($($t:tt)*) => { $crate::log_at!($crate::logging::LogLevel::Debug, $($t)*) }
+}
+
+#[macro_export]
+macro_rules! trace_log {
+    ($($t:tt)*) => { $crate::log_at!($crate::logging::LogLevel::Trace, $($t)*) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    struct CollectingLogger(Arc<Mutex<Vec<(LogLevel, String)>>>);
+
+    impl Logger for CollectingLogger {
+        fn log(&self, level: LogLevel, message: &str) {
+            if let Ok(mut messages) = self.0.lock() {
+                messages.push((level, message.to_string()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_messages_reach_logger_by_level() {
+        let messages = Arc::new(Mutex::new(Vec::new()));
+        let logger = CollectingLogger(Arc::clone(&messages));
+        let max_level = LogLevel::Info as u8;
+        log_to(&logger, max_level, LogLevel::Warn, "Sorted 3 class strings");
+        log_to(
+            &logger,
+            max_level,
+            LogLevel::Trace,
+            "Hidden at the default level",
+        );
+
+        let messages = messages.lock().unwrap();
+        assert!(messages.contains(&(LogLevel::Warn, "Sorted 3 class strings".to_string())));
+        assert!(
+            !messages
+                .iter()
+                .any(|(_, message)| message == "Hidden at the default level")
+        );
+    }
+
+    #[test]
+    fn test_off_passes_nothing() {
+        assert!(passes(LogLevel::Error, LogLevel::Error as u8));
+        assert!(!passes(LogLevel::Error, 0));
+        assert!(!passes(LogLevel::Debug, LogLevel::Info as u8));
+    }
+
+    #[test]
+    fn test_level_names() {
+        assert_eq!(LogLevel::from_name("debug"), Some(LogLevel::Debug));
+        assert_eq!(LogLevel::from_name("verbose"), None);
+        assert_eq!(LogLevel::Trace.as_str(), "trace");
+        assert!(LogLevel::Error < LogLevel::Trace);
+    }
+}
@@ -0,0 +1,676 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+use serde_json::{Value, json};
+
+use crate::check::check_classes;
+use crate::ranges::TextRange;
+use crate::report::{UNSORTED_CLASSES_RULE_ID, find_unsorted};
+use crate::sorter::{
+    OutputMode, set_normalize_whitespace, set_output_mode, set_print_width, set_remove_duplicates,
+};
+use crate::{class_edits_in_ranges, sort_document};
+
+/// Code action kind for sorting every class string in a document
+pub const SORT_CLASSES_ACTION_KIND: &str = "source.sortTailwindClasses";
+
+const SERVER_NAME: &str = "tailwind-sort-lsp";
+const DIAGNOSTIC_SOURCE: &str = "tailwind-sort";
+
+// JSON-RPC error codes
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_REQUEST: i64 = -32600;
+const INVALID_PARAMS: i64 = -32602;
+
+// LSP enum values
+const TEXT_DOCUMENT_SYNC_FULL: u8 = 1;
+const DIAGNOSTIC_SEVERITY_WARNING: u8 = 2;
+
+/// An open text document
+#[derive(Debug, Clone)]
+struct Document {
+    text: String,
+    file_extension: String,
+}
+
+/// A language server exposing formatting, diagnostics and code actions for class sorting.
+///
+/// The server is transport agnostic: `handle_message` takes one incoming JSON-RPC message
+/// and returns the messages to send back. `run_stdio` wires it up to stdin and stdout.
+#[derive(Debug, Default)]
+pub struct LanguageServer {
+    documents: HashMap<String, Document>,
+    shutdown_requested: bool,
+    exit_code: Option<i32>,
+}
+
+impl LanguageServer {
+    pub fn new() -> Self {
+        LanguageServer::default()
+    }
+
+    /// The process exit code, once the client has sent `exit`
+    pub fn exit_code(&self) -> Option<i32> {
+        self.exit_code
+    }
+
+    /// Handle one incoming message and return the outgoing messages it produces
+    pub fn handle_message(&mut self, message: &Value) -> Vec<Value> {
+        let method = message["method"].as_str().unwrap_or_default();
+        let params = &message["params"];
+
+        // Messages without an id are notifications and never get a response
+        let Some(id) = message.get("id").cloned() else {
+            return self.handle_notification(method, params);
+        };
+
+        if self.shutdown_requested {
+            return vec![error_response(
+                id,
+                INVALID_REQUEST,
+                "Server is shutting down",
+            )];
+        }
+
+        let result = match method {
+            "initialize" => Ok(self.initialize(params)),
+            "shutdown" => {
+                self.shutdown_requested = true;
+                Ok(Value::Null)
+            }
+            "textDocument/formatting" => self.formatting(params, None),
+            "textDocument/rangeFormatting" => self.formatting(params, Some(&params["range"])),
+            "textDocument/codeAction" => self.code_actions(params),
+            _ => Err((METHOD_NOT_FOUND, format!("Unhandled method {}", method))),
+        };
+
+        match result {
+            Ok(result) => vec![json!({ "jsonrpc": "2.0", "id": id, "result": result })],
+            Err((code, message)) => vec![error_response(id, code, &message)],
+        }
+    }
+
+    fn handle_notification(&mut self, method: &str, params: &Value) -> Vec<Value> {
+        match method {
+            "textDocument/didOpen" => {
+                let item = &params["textDocument"];
+                let (Some(uri), Some(text)) = (item["uri"].as_str(), item["text"].as_str()) else {
+                    return vec![];
+                };
+                let language_id = item["languageId"].as_str().unwrap_or_default();
+
+                self.documents.insert(
+                    uri.to_string(),
+                    Document {
+                        text: text.to_string(),
+                        file_extension: file_extension(uri, language_id),
+                    },
+                );
+                self.publish_diagnostics(uri)
+            }
+            "textDocument/didChange" => {
+                let Some(uri) = params["textDocument"]["uri"].as_str() else {
+                    return vec![];
+                };
+
+                // We only advertise full sync, so the last change holds the whole text
+                let text = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str());
+
+                if let (Some(document), Some(text)) = (self.documents.get_mut(uri), text) {
+                    document.text = text.to_string();
+                }
+                self.publish_diagnostics(uri)
+            }
+            "textDocument/didClose" => {
+                let Some(uri) = params["textDocument"]["uri"].as_str() else {
+                    return vec![];
+                };
+                self.documents.remove(uri);
+
+                // Clear any diagnostics left for the closed document
+                vec![notification(
+                    "textDocument/publishDiagnostics",
+                    json!({ "uri": uri, "diagnostics": [] }),
+                )]
+            }
+            "exit" => {
+                self.exit_code = Some(if self.shutdown_requested { 0 } else { 1 });
+                vec![]
+            }
+            _ => vec![],
+        }
+    }
+
+    fn initialize(&mut self, params: &Value) -> Value {
+        configure(&params["initializationOptions"]);
+
+        json!({
+            "capabilities": {
+                "textDocumentSync": TEXT_DOCUMENT_SYNC_FULL,
+                "documentFormattingProvider": true,
+                "documentRangeFormattingProvider": true,
+                "codeActionProvider": {
+                    "codeActionKinds": ["quickfix", SORT_CLASSES_ACTION_KIND],
+                },
+            },
+            "serverInfo": {
+                "name": SERVER_NAME,
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+        })
+    }
+
+    fn document(&self, params: &Value) -> Result<(&str, &Document), (i64, String)> {
+        let uri = params["textDocument"]["uri"]
+            .as_str()
+            .ok_or((INVALID_PARAMS, "Missing textDocument.uri".to_string()))?;
+
+        self.documents
+            .get_key_value(uri)
+            .map(|(uri, document)| (uri.as_str(), document))
+            .ok_or((INVALID_PARAMS, format!("Unknown document {}", uri)))
+    }
+
+    /// Format the whole document, or only the class strings intersecting `range`.
+    ///
+    /// Returns one edit per rewritten class string, so the rest of the document is untouched.
+    fn formatting(&self, params: &Value, range: Option<&Value>) -> Result<Value, (i64, String)> {
+        let (_, document) = self.document(params)?;
+        let text = &document.text;
+
+        let range = match range {
+            Some(range) => TextRange {
+                start: position_to_offset(text, &range["start"]),
+                end: position_to_offset(text, &range["end"]),
+            },
+            None => TextRange {
+                start: 0,
+                end: text.len(),
+            },
+        };
+
+        let edits: Vec<Value> = class_edits_in_ranges(text, &document.file_extension, &[range])
+            .into_iter()
+            .rev()
+            .map(|edit| {
+                json!({
+                    "range": lsp_range(text, edit.start, edit.end),
+                    "newText": edit.replacement,
+                })
+            })
+            .collect();
+
+        Ok(json!(edits))
+    }
+
+    /// Offer a quick fix per unsorted class string in the range, plus a whole-document action
+    fn code_actions(&self, params: &Value) -> Result<Value, (i64, String)> {
+        let (uri, document) = self.document(params)?;
+        let text = &document.text;
+        let range = TextRange {
+            start: position_to_offset(text, &params["range"]["start"]),
+            end: position_to_offset(text, &params["range"]["end"]),
+        };
+
+        let findings = find_unsorted(text, &document.file_extension, uri).unwrap_or_default();
+        if findings.is_empty() {
+            return Ok(json!([]));
+        }
+
+        let mut actions: Vec<Value> = findings
+            .iter()
+            .filter(|finding| {
+                let start = finding.region.byte_offset;
+                range.intersects(start, start + finding.region.byte_length)
+            })
+            .map(|finding| {
+                let fix_start = finding.fix.region.byte_offset;
+                let fix_end = fix_start + finding.fix.region.byte_length;
+
+                json!({
+                    "title": "Sort Tailwind classes",
+                    "kind": "quickfix",
+                    "isPreferred": true,
+                    "diagnostics": [diagnostic(
+                        text,
+                        finding.region.byte_offset,
+                        finding.region.byte_offset + finding.region.byte_length,
+                        &finding.fix.replacement,
+                    )],
+                    "edit": {
+                        "changes": {
+                            uri: [{
+                                "range": lsp_range(text, fix_start, fix_end),
+                                "newText": finding.fix.replacement,
+                            }],
+                        },
+                    },
+                })
+            })
+            .collect();
+
+        let sorted = sort_document(text, &document.file_extension);
+        actions.push(json!({
+            "title": "Sort all Tailwind classes in file",
+            "kind": SORT_CLASSES_ACTION_KIND,
+            "edit": {
+                "changes": {
+                    uri: [{
+                        "range": lsp_range(text, 0, text.len()),
+                        "newText": sorted,
+                    }],
+                },
+            },
+        }));
+
+        Ok(Value::Array(actions))
+    }
+
+    fn publish_diagnostics(&self, uri: &str) -> Vec<Value> {
+        let Some(document) = self.documents.get(uri) else {
+            return vec![];
+        };
+
+        // A document that doesn't parse (often mid-edit) has no class strings to report
+        let unsorted = check_classes(&document.text, &document.file_extension).unwrap_or_default();
+        let diagnostics: Vec<Value> = unsorted
+            .iter()
+            .map(|unsorted| {
+                diagnostic(
+                    &document.text,
+                    unsorted.start,
+                    unsorted.end,
+                    &unsorted.expected,
+                )
+            })
+            .collect();
+
+        vec![notification(
+            "textDocument/publishDiagnostics",
+            json!({ "uri": uri, "diagnostics": diagnostics }),
+        )]
+    }
+}
+
+/// Apply sorter options passed as `initializationOptions`
+fn configure(options: &Value) {
+    if let Some(remove) = options["removeDuplicates"].as_bool() {
+        set_remove_duplicates(remove);
+    }
+    if let Some(normalize) = options["normalizeWhitespace"].as_bool() {
+        set_normalize_whitespace(normalize);
+    }
+    if let Some(width) = options["printWidth"].as_u64() {
+        set_print_width(width as usize);
+    }
+    if let Some(mode) = options["outputMode"]
+        .as_str()
+        .and_then(OutputMode::from_name)
+    {
+        set_output_mode(mode);
+    }
+}
+
+fn diagnostic(text: &str, start: usize, end: usize, expected: &str) -> Value {
+    json!({
+        "range": lsp_range(text, start, end),
+        "severity": DIAGNOSTIC_SEVERITY_WARNING,
+        "code": UNSORTED_CLASSES_RULE_ID,
+        "source": DIAGNOSTIC_SOURCE,
+        "message": format!("Tailwind classes are not sorted. Expected \"{}\".", expected),
+    })
+}
+
+fn notification(method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "method": method, "params": params })
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+}
+
+/// Pick the parser extension from the document URI, falling back to the language id
+fn file_extension(uri: &str, language_id: &str) -> String {
+    let file_name = uri.rsplit('/').next().unwrap_or(uri);
+    if let Some((_, extension)) = file_name.rsplit_once('.') {
+        return extension.to_string();
+    }
+
+    match language_id {
+        "typescriptreact" => "tsx",
+        "javascriptreact" => "jsx",
+        "typescript" => "ts",
+        "javascript" => "js",
+        other => other,
+    }
+    .to_string()
+}
+
+/// Convert a byte offset into an LSP position (zero-based line, UTF-16 character)
+pub fn offset_to_position(text: &str, offset: usize) -> (usize, usize) {
+    let offset = offset.min(text.len());
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |pos| pos + 1);
+
+    (
+        before.matches('\n').count(),
+        before[line_start..].encode_utf16().count(),
+    )
+}
+
+/// Convert an LSP position into a byte offset, clamped to the line and document
+pub fn position_to_offset(text: &str, position: &Value) -> usize {
+    let line = position["line"].as_u64().unwrap_or(0) as usize;
+    let character = position["character"].as_u64().unwrap_or(0) as usize;
+
+    let Some(line_start) = (if line == 0 {
+        Some(0)
+    } else {
+        text.match_indices('\n')
+            .nth(line - 1)
+            .map(|(pos, _)| pos + 1)
+    }) else {
+        return text.len();
+    };
+
+    let mut utf16_offset = 0;
+    for (byte_offset, c) in text[line_start..].char_indices() {
+        if utf16_offset >= character || c == '\n' {
+            return line_start + byte_offset;
+        }
+        utf16_offset += c.len_utf16();
+    }
+
+    text.len()
+}
+
+fn lsp_range(text: &str, start: usize, end: usize) -> Value {
+    let (start_line, start_character) = offset_to_position(text, start);
+    let (end_line, end_character) = offset_to_position(text, end);
+
+    json!({
+        "start": { "line": start_line, "character": start_character },
+        "end": { "line": end_line, "character": end_character },
+    })
+}
+
+/// Read one `Content-Length` framed message. Returns `None` at end of input.
+///
+/// A body that is not valid JSON is returned as an inner error, so the caller can answer
+/// with a parse error and keep reading. Only broken framing is an I/O error.
+pub fn read_message(reader: &mut impl BufRead) -> io::Result<Option<serde_json::Result<Value>>> {
+    let mut content_length = None;
+
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':')
+            && name.eq_ignore_ascii_case("Content-Length")
+        {
+            content_length = value.trim().parse::<usize>().ok();
+        }
+    }
+
+    let Some(content_length) = content_length else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "Missing Content-Length header",
+        ));
+    };
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    Ok(Some(serde_json::from_slice(&body)))
+}
+
+/// Write one `Content-Length` framed message
+pub fn write_message(writer: &mut impl Write, message: &Value) -> io::Result<()> {
+    let body = message.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    writer.flush()
+}
+
+/// Serve the protocol over stdin and stdout until the client sends `exit`.
+///
+/// Returns the exit code the process should use.
+pub fn run_stdio() -> io::Result<i32> {
+    let stdin = io::stdin();
+    serve(&mut stdin.lock(), &mut io::stdout())
+}
+
+/// Serve the protocol over a reader and writer until the client sends `exit`
+fn serve(reader: &mut impl BufRead, writer: &mut impl Write) -> io::Result<i32> {
+    let mut server = LanguageServer::new();
+
+    while let Some(message) = read_message(reader)? {
+        let message = match message {
+            Ok(message) => message,
+            Err(_) => {
+                // The id of an unparsable request is unknown, so JSON-RPC answers with null
+                let response = error_response(Value::Null, PARSE_ERROR, "Parse error");
+                write_message(writer, &response)?;
+                continue;
+            }
+        };
+
+        for response in server.handle_message(&message) {
+            write_message(writer, &response)?;
+        }
+
+        if let Some(code) = server.exit_code() {
+            return Ok(code);
+        }
+    }
+
+    // The client went away without `exit`
+    Ok(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URI: &str = "file:///project/src/App.tsx";
+    const TEXT: &str = "const a = <div className=\"p-4 flex mt-2\" />;\n";
+
+    fn open_server() -> (LanguageServer, Vec<Value>) {
+        let mut server = LanguageServer::new();
+        server.handle_message(&json!({
+            "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}
+        }));
+        let messages = server.handle_message(&json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": {
+                "textDocument": {
+                    "uri": URI, "languageId": "typescriptreact", "version": 1, "text": TEXT
+                }
+            }
+        }));
+        (server, messages)
+    }
+
+    #[test]
+    fn test_initialize_advertises_capabilities() {
+        let mut server = LanguageServer::new();
+        let response = server.handle_message(&json!({
+            "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}
+        }));
+        let capabilities = &response[0]["result"]["capabilities"];
+
+        assert_eq!(capabilities["documentFormattingProvider"], true);
+        assert_eq!(capabilities["documentRangeFormattingProvider"], true);
+        assert_eq!(capabilities["textDocumentSync"], 1);
+    }
+
+    #[test]
+    fn test_did_open_publishes_diagnostics() {
+        let (_, messages) = open_server();
+
+        assert_eq!(messages[0]["method"], "textDocument/publishDiagnostics");
+        let diagnostic = &messages[0]["params"]["diagnostics"][0];
+        assert_eq!(diagnostic["code"], UNSORTED_CLASSES_RULE_ID);
+        assert_eq!(diagnostic["range"]["start"]["character"], 25);
+        assert_eq!(diagnostic["range"]["end"]["character"], 40);
+    }
+
+    #[test]
+    fn test_formatting_returns_class_string_edits() {
+        let (mut server, _) = open_server();
+        let response = server.handle_message(&json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "textDocument/formatting",
+            "params": { "textDocument": { "uri": URI }, "options": {} }
+        }));
+
+        let edit = &response[0]["result"][0];
+        assert_eq!(edit["newText"], "\"mt-2 flex p-4\"");
+        assert_eq!(edit["range"]["start"]["character"], 25);
+        assert_eq!(edit["range"]["end"]["character"], 40);
+    }
+
+    #[test]
+    fn test_range_formatting_stays_within_range() {
+        let mut server = LanguageServer::new();
+        let text = "const a = \"p-4 flex mt-2\";\nconst b = \"p-4 flex mt-2\";\n";
+        server.handle_message(&json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": {
+                "textDocument": {
+                    "uri": URI, "languageId": "typescript", "version": 1, "text": text
+                }
+            }
+        }));
+
+        let response = server.handle_message(&json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "textDocument/rangeFormatting",
+            "params": {
+                "textDocument": { "uri": URI },
+                "range": {
+                    "start": { "line": 1, "character": 0 },
+                    "end": { "line": 1, "character": 26 }
+                },
+                "options": {}
+            }
+        }));
+
+        let edits = response[0]["result"].as_array().unwrap();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(
+            edits[0]["range"]["start"],
+            json!({ "line": 1, "character": 10 })
+        );
+        assert_eq!(
+            edits[0]["range"]["end"],
+            json!({ "line": 1, "character": 25 })
+        );
+        assert_eq!(edits[0]["newText"], "\"mt-2 flex p-4\"");
+    }
+
+    #[test]
+    fn test_code_action_replaces_class_string() {
+        let (mut server, _) = open_server();
+        let response = server.handle_message(&json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "textDocument/codeAction",
+            "params": {
+                "textDocument": { "uri": URI },
+                "range": {
+                    "start": { "line": 0, "character": 30 },
+                    "end": { "line": 0, "character": 30 }
+                },
+                "context": { "diagnostics": [] }
+            }
+        }));
+        let actions = response[0]["result"].as_array().unwrap();
+
+        assert_eq!(actions.len(), 2);
+        assert_eq!(actions[0]["kind"], "quickfix");
+        let edit = &actions[0]["edit"]["changes"][URI][0];
+        assert_eq!(edit["newText"], "mt-2 flex p-4");
+        assert_eq!(edit["range"]["start"]["character"], 26);
+        assert_eq!(actions[1]["kind"], SORT_CLASSES_ACTION_KIND);
+    }
+
+    #[test]
+    fn test_unknown_request_and_shutdown() {
+        let mut server = LanguageServer::new();
+        let response = server.handle_message(&json!({
+            "jsonrpc": "2.0", "id": 1, "method": "workspace/symbol", "params": {}
+        }));
+        assert_eq!(response[0]["error"]["code"], METHOD_NOT_FOUND);
+
+        server.handle_message(&json!({ "jsonrpc": "2.0", "id": 2, "method": "shutdown" }));
+        server.handle_message(&json!({ "jsonrpc": "2.0", "method": "exit" }));
+        assert_eq!(server.exit_code(), Some(0));
+    }
+
+    #[test]
+    fn test_position_conversions() {
+        let text = "ab\n😀c\n";
+        assert_eq!(offset_to_position(text, 7), (1, 2));
+        assert_eq!(
+            position_to_offset(text, &json!({ "line": 1, "character": 2 })),
+            7
+        );
+        assert_eq!(
+            position_to_offset(text, &json!({ "line": 0, "character": 99 })),
+            2
+        );
+    }
+
+    #[test]
+    fn test_message_framing() {
+        let mut buffer = Vec::new();
+        write_message(&mut buffer, &json!({ "jsonrpc": "2.0", "method": "exit" })).unwrap();
+
+        let message = read_message(&mut buffer.as_slice())
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(message["method"], "exit");
+        assert!(read_message(&mut &b""[..]).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_malformed_json_gets_parse_error() {
+        let mut input = b"Content-Length: 5\r\n\r\n{bad}".to_vec();
+        let shutdown = json!({ "jsonrpc": "2.0", "id": 1, "method": "shutdown" });
+        write_message(&mut input, &shutdown).unwrap();
+        write_message(&mut input, &json!({ "jsonrpc": "2.0", "method": "exit" })).unwrap();
+
+        let mut output = Vec::new();
+        let code = serve(&mut input.as_slice(), &mut output).unwrap();
+
+        // The server answers the bad body and still handles the shutdown that follows it
+        assert_eq!(code, 0);
+        let response = read_message(&mut output.as_slice())
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(response["error"]["code"], PARSE_ERROR);
+        assert_eq!(response["id"], Value::Null);
+    }
+}
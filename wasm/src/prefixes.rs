@@ -1,5 +1,6 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock, RwLock};
 
 /// ValueType represents the allowed value types for a Tailwind CSS prefix
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -14,14 +15,31 @@ pub enum ValueType {
     ArbitraryValue, // For arbitrary values: p-[10px]
     Length,         // For length values
     Scale,          // For size scales: text-sm, rounded-lg
+    Any,            // For custom utilities that take any value: tab-4
+}
+
+impl ValueType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ValueType::None => "none",
+            ValueType::Number => "number",
+            ValueType::Fraction => "fraction",
+            ValueType::Color => "color",
+            ValueType::ColorPalette => "color-palette",
+            ValueType::CustomProperty => "custom-property",
+            ValueType::ArbitraryValue => "arbitrary-value",
+            ValueType::Length => "length",
+            ValueType::Scale => "scale",
+            ValueType::Any => "any",
+        }
+    }
 }
 
 /// TailwindPrefix stores metadata about a Tailwind CSS class prefix
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TailwindPrefix {
     pub order: usize,
-    pub prefix: &'static str,
-    #[allow(dead_code)]
+    pub prefix: Cow<'static, str>,
     pub value_types: &'static [ValueType],
     #[allow(dead_code)]
     pub allowed_values: &'static [&'static str],
@@ -343,16 +361,44 @@ pub fn find_order(css_property: &str) -> usize {
     1000
 }
 
-/// Get a comprehensive list of all Tailwind CSS class prefixes
-pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
-    static TAILWIND_PREFIXES: OnceLock<Vec<TailwindPrefix>> = OnceLock::new();
+/// The built-in prefixes extended with the project's custom utilities, if any
+static EXTENDED_PREFIXES: RwLock<Option<Arc<Vec<TailwindPrefix>>>> = RwLock::new(None);
+
+/// Get a comprehensive list of all Tailwind CSS class prefixes, including custom utilities
+pub fn get_tailwind_prefixes() -> Arc<Vec<TailwindPrefix>> {
+    if let Ok(extended) = EXTENDED_PREFIXES.read()
+        && let Some(prefixes) = extended.as_ref()
+    {
+        return Arc::clone(prefixes);
+    }
+
+    Arc::clone(default_prefixes())
+}
+
+/// Add custom utilities to the prefix table, replacing any added before
+pub fn set_custom_prefixes(custom: Vec<TailwindPrefix>) {
+    let extended = if custom.is_empty() {
+        None
+    } else {
+        let mut prefixes = default_prefixes().as_ref().clone();
+        prefixes.extend(custom);
+        Some(Arc::new(prefixes))
+    };
+
+    if let Ok(mut value) = EXTENDED_PREFIXES.write() {
+        *value = extended;
+    }
+}
+
+fn default_prefixes() -> &'static Arc<Vec<TailwindPrefix>> {
+    static TAILWIND_PREFIXES: OnceLock<Arc<Vec<TailwindPrefix>>> = OnceLock::new();
 
     TAILWIND_PREFIXES.get_or_init(|| {
-        vec![
+        Arc::new(vec![
             // ===== LAYOUT - ISOLATION =====
             TailwindPrefix {
                 order: find_order("isolation"),
-                prefix: "isolate",
+                prefix: Cow::Borrowed("isolate"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -360,14 +406,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== MISC - PERSPECTIVE =====
             TailwindPrefix {
                 order: find_order("transform") - 1,
-                prefix: "perspective-",
+                prefix: Cow::Borrowed("perspective-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["dramatic", "near", "normal", "midrange", "distant", "none"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("transform") - 1,
-                prefix: "perspective-origin-",
+                prefix: Cow::Borrowed("perspective-origin-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &[
                     "center",
@@ -385,7 +431,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== MISC - ORDER =====
             TailwindPrefix {
                 order: find_order("order"),
-                prefix: "order-",
+                prefix: Cow::Borrowed("order-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -397,14 +443,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== SCREENREADERS =====
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "sr-only",
+                prefix: Cow::Borrowed("sr-only"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "not-sr-only",
+                prefix: Cow::Borrowed("not-sr-only"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -412,7 +458,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - FILTER SATURATE =====
             TailwindPrefix {
                 order: find_order("filter"),
-                prefix: "saturate-",
+                prefix: Cow::Borrowed("saturate-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -424,14 +470,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - FILTER SEPIA =====
             TailwindPrefix {
                 order: find_order("filter"),
-                prefix: "sepia",
+                prefix: Cow::Borrowed("sepia"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("filter"),
-                prefix: "sepia-",
+                prefix: Cow::Borrowed("sepia-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -443,14 +489,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - FILTER GENERAL =====
             TailwindPrefix {
                 order: find_order("filter"),
-                prefix: "filter",
+                prefix: Cow::Borrowed("filter"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("filter"),
-                prefix: "filter-",
+                prefix: Cow::Borrowed("filter-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["none"],
                 is_standalone: false,
@@ -458,7 +504,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - BACKDROP FILTER BLUR =====
             TailwindPrefix {
                 order: find_order("backdrop-filter"),
-                prefix: "backdrop-blur-",
+                prefix: Cow::Borrowed("backdrop-blur-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["xs", "sm", "md", "lg", "xl", "2xl", "3xl", "none"],
                 is_standalone: false,
@@ -466,7 +512,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - BACKDROP FILTER BRIGHTNESS =====
             TailwindPrefix {
                 order: find_order("backdrop-filter"),
-                prefix: "backdrop-brightness-",
+                prefix: Cow::Borrowed("backdrop-brightness-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -478,7 +524,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - BACKDROP FILTER CONTRAST =====
             TailwindPrefix {
                 order: find_order("backdrop-filter"),
-                prefix: "backdrop-contrast-",
+                prefix: Cow::Borrowed("backdrop-contrast-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -490,14 +536,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - BACKDROP FILTER GRAYSCALE =====
             TailwindPrefix {
                 order: find_order("backdrop-filter"),
-                prefix: "backdrop-grayscale",
+                prefix: Cow::Borrowed("backdrop-grayscale"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("backdrop-filter"),
-                prefix: "backdrop-grayscale-",
+                prefix: Cow::Borrowed("backdrop-grayscale-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -509,7 +555,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - BACKDROP FILTER HUE ROTATE =====
             TailwindPrefix {
                 order: find_order("backdrop-filter"),
-                prefix: "backdrop-hue-rotate-",
+                prefix: Cow::Borrowed("backdrop-hue-rotate-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -521,14 +567,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - BACKDROP FILTER INVERT =====
             TailwindPrefix {
                 order: find_order("backdrop-filter"),
-                prefix: "backdrop-invert",
+                prefix: Cow::Borrowed("backdrop-invert"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("backdrop-filter"),
-                prefix: "backdrop-invert-",
+                prefix: Cow::Borrowed("backdrop-invert-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -540,7 +586,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - BACKDROP FILTER OPACITY =====
             TailwindPrefix {
                 order: find_order("backdrop-filter"),
-                prefix: "backdrop-opacity-",
+                prefix: Cow::Borrowed("backdrop-opacity-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -552,7 +598,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - BACKDROP FILTER SATURATE =====
             TailwindPrefix {
                 order: find_order("backdrop-filter"),
-                prefix: "backdrop-saturate-",
+                prefix: Cow::Borrowed("backdrop-saturate-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -564,14 +610,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - BACKDROP FILTER SEPIA =====
             TailwindPrefix {
                 order: find_order("backdrop-filter"),
-                prefix: "backdrop-sepia",
+                prefix: Cow::Borrowed("backdrop-sepia"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("backdrop-filter"),
-                prefix: "backdrop-sepia-",
+                prefix: Cow::Borrowed("backdrop-sepia-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -583,7 +629,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - BACKDROP FILTER GENERAL =====
             TailwindPrefix {
                 order: find_order("backdrop-filter"),
-                prefix: "backdrop-filter-",
+                prefix: Cow::Borrowed("backdrop-filter-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["none"],
                 is_standalone: false,
@@ -591,14 +637,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TABLES - BORDER COLLAPSE =====
             TailwindPrefix {
                 order: find_order("border-collapse"),
-                prefix: "border-collapse",
+                prefix: Cow::Borrowed("border-collapse"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("border-collapse"),
-                prefix: "border-separate",
+                prefix: Cow::Borrowed("border-separate"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -606,7 +652,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TABLES - BORDER SPACING =====
             TailwindPrefix {
                 order: find_order("border-spacing"),
-                prefix: "border-spacing-",
+                prefix: Cow::Borrowed("border-spacing-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -617,7 +663,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-spacing"),
-                prefix: "border-spacing-x-",
+                prefix: Cow::Borrowed("border-spacing-x-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -628,7 +674,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-spacing"),
-                prefix: "border-spacing-y-",
+                prefix: Cow::Borrowed("border-spacing-y-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -640,14 +686,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TABLES - TABLE LAYOUT =====
             TailwindPrefix {
                 order: find_order("table-layout"),
-                prefix: "table-auto",
+                prefix: Cow::Borrowed("table-auto"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("table-layout"),
-                prefix: "table-fixed",
+                prefix: Cow::Borrowed("table-fixed"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -655,14 +701,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TABLES - CAPTION SIDE =====
             TailwindPrefix {
                 order: find_order("caption-side"),
-                prefix: "caption-top",
+                prefix: Cow::Borrowed("caption-top"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("caption-side"),
-                prefix: "caption-bottom",
+                prefix: Cow::Borrowed("caption-bottom"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -670,14 +716,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TRANSITIONS - TRANSITION PROPERTY =====
             TailwindPrefix {
                 order: find_order("transition-property"),
-                prefix: "transition",
+                prefix: Cow::Borrowed("transition"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("transition-property"),
-                prefix: "transition-",
+                prefix: Cow::Borrowed("transition-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["none", "all", "colors", "opacity", "shadow", "transform"],
                 is_standalone: false,
@@ -685,7 +731,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TRANSITIONS - TRANSITION DURATION =====
             TailwindPrefix {
                 order: find_order("transition-duration"),
-                prefix: "duration-",
+                prefix: Cow::Borrowed("duration-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -697,7 +743,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TRANSITIONS - TRANSITION TIMING FUNCTION =====
             TailwindPrefix {
                 order: find_order("transition-timing-function"),
-                prefix: "ease-",
+                prefix: Cow::Borrowed("ease-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["linear", "in", "out", "in-out", "initial"],
                 is_standalone: false,
@@ -705,7 +751,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TRANSITIONS - TRANSITION DELAY =====
             TailwindPrefix {
                 order: find_order("transition-delay"),
-                prefix: "delay-",
+                prefix: Cow::Borrowed("delay-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -717,7 +763,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TRANSITIONS - TRANSITION BEHAVIOR =====
             TailwindPrefix {
                 order: find_order("transition-behavior"),
-                prefix: "transition-",
+                prefix: Cow::Borrowed("transition-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["normal", "discrete"],
                 is_standalone: false,
@@ -725,7 +771,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TRANSFORMS - SCALE =====
             TailwindPrefix {
                 order: find_order("scale"),
-                prefix: "scale-",
+                prefix: Cow::Borrowed("scale-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -736,7 +782,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scale"),
-                prefix: "scale-x-",
+                prefix: Cow::Borrowed("scale-x-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -747,7 +793,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scale"),
-                prefix: "scale-y-",
+                prefix: Cow::Borrowed("scale-y-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -758,7 +804,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scale"),
-                prefix: "scale-z-",
+                prefix: Cow::Borrowed("scale-z-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -769,7 +815,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scale"),
-                prefix: "scale-3d",
+                prefix: Cow::Borrowed("scale-3d"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -777,7 +823,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TRANSFORMS - ROTATE =====
             TailwindPrefix {
                 order: find_order("rotate"),
-                prefix: "rotate-",
+                prefix: Cow::Borrowed("rotate-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -788,7 +834,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("rotate"),
-                prefix: "rotate-x-",
+                prefix: Cow::Borrowed("rotate-x-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -799,7 +845,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("rotate"),
-                prefix: "rotate-y-",
+                prefix: Cow::Borrowed("rotate-y-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -810,7 +856,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("rotate"),
-                prefix: "rotate-z-",
+                prefix: Cow::Borrowed("rotate-z-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -822,7 +868,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TRANSFORMS - TRANSLATE =====
             TailwindPrefix {
                 order: find_order("translate"),
-                prefix: "translate-",
+                prefix: Cow::Borrowed("translate-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -834,7 +880,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("translate-x"),
-                prefix: "translate-x-",
+                prefix: Cow::Borrowed("translate-x-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -846,7 +892,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("translate-y"),
-                prefix: "translate-y-",
+                prefix: Cow::Borrowed("translate-y-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -858,7 +904,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("translate"),
-                prefix: "translate-z-",
+                prefix: Cow::Borrowed("translate-z-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -870,7 +916,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TRANSFORMS - SKEW =====
             TailwindPrefix {
                 order: find_order("transform"),
-                prefix: "skew-",
+                prefix: Cow::Borrowed("skew-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -881,7 +927,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("transform"),
-                prefix: "skew-x-",
+                prefix: Cow::Borrowed("skew-x-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -892,7 +938,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("transform"),
-                prefix: "skew-y-",
+                prefix: Cow::Borrowed("skew-y-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -904,7 +950,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TRANSFORMS - TRANSFORM ORIGIN =====
             TailwindPrefix {
                 order: find_order("transform-origin"),
-                prefix: "origin-",
+                prefix: Cow::Borrowed("origin-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &[
                     "center",
@@ -922,14 +968,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TRANSFORMS - TRANSFORM STYLE =====
             TailwindPrefix {
                 order: find_order("transform"),
-                prefix: "transform-3d",
+                prefix: Cow::Borrowed("transform-3d"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("transform"),
-                prefix: "transform-flat",
+                prefix: Cow::Borrowed("transform-flat"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -937,14 +983,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TRANSFORMS - TRANSFORM =====
             TailwindPrefix {
                 order: find_order("transform"),
-                prefix: "transform",
+                prefix: Cow::Borrowed("transform"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("transform"),
-                prefix: "transform-",
+                prefix: Cow::Borrowed("transform-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["none", "gpu", "cpu"],
                 is_standalone: false,
@@ -952,7 +998,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== INTERACTIVITY - APPEARANCE =====
             TailwindPrefix {
                 order: find_order("appearance"),
-                prefix: "appearance-",
+                prefix: Cow::Borrowed("appearance-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["none", "auto"],
                 is_standalone: false,
@@ -960,7 +1006,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== INTERACTIVITY - CURSOR =====
             TailwindPrefix {
                 order: find_order("cursor"),
-                prefix: "cursor-",
+                prefix: Cow::Borrowed("cursor-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &[
                     "auto",
@@ -1005,7 +1051,20 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== INTERACTIVITY - CARET COLOR =====
             TailwindPrefix {
                 order: find_order("caret-color"),
-                prefix: "caret-",
+                prefix: Cow::Borrowed("caret-"),
+                value_types: &[
+                    ValueType::Color,
+                    ValueType::ColorPalette,
+                    ValueType::CustomProperty,
+                    ValueType::ArbitraryValue,
+                ],
+                allowed_values: &["inherit", "current", "transparent", "black", "white"],
+                is_standalone: false,
+            },
+            // ===== INTERACTIVITY - PLACEHOLDER COLOR =====
+            TailwindPrefix {
+                order: find_order("placeholder-color"),
+                prefix: Cow::Borrowed("placeholder-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -1018,7 +1077,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== INTERACTIVITY - POINTER EVENTS =====
             TailwindPrefix {
                 order: find_order("pointer-events"),
-                prefix: "pointer-events-",
+                prefix: Cow::Borrowed("pointer-events-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["auto", "none"],
                 is_standalone: false,
@@ -1026,14 +1085,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== INTERACTIVITY - RESIZE =====
             TailwindPrefix {
                 order: find_order("resize"),
-                prefix: "resize",
+                prefix: Cow::Borrowed("resize"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("resize"),
-                prefix: "resize-",
+                prefix: Cow::Borrowed("resize-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["none", "x", "y"],
                 is_standalone: false,
@@ -1041,7 +1100,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== INTERACTIVITY - TOUCH ACTION =====
             TailwindPrefix {
                 order: find_order("touch-action"),
-                prefix: "touch-",
+                prefix: Cow::Borrowed("touch-"),
                 value_types: &[ValueType::None],
                 allowed_values: &[
                     "auto",
@@ -1060,7 +1119,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== INTERACTIVITY - USER SELECT =====
             TailwindPrefix {
                 order: find_order("user-select") + 1,
-                prefix: "select-",
+                prefix: Cow::Borrowed("select-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["none", "text", "all", "auto"],
                 is_standalone: false,
@@ -1068,7 +1127,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== INTERACTIVITY - SCROLL BEHAVIOR =====
             TailwindPrefix {
                 order: find_order("scroll-behavior"),
-                prefix: "scroll-",
+                prefix: Cow::Borrowed("scroll-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["auto", "smooth"],
                 is_standalone: false,
@@ -1076,7 +1135,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== INTERACTIVITY - SCROLL MARGIN =====
             TailwindPrefix {
                 order: find_order("scroll-margin"),
-                prefix: "scroll-m-",
+                prefix: Cow::Borrowed("scroll-m-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1087,7 +1146,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scroll-margin"),
-                prefix: "scroll-mx-",
+                prefix: Cow::Borrowed("scroll-mx-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1098,7 +1157,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scroll-margin"),
-                prefix: "scroll-my-",
+                prefix: Cow::Borrowed("scroll-my-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1109,7 +1168,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scroll-margin"),
-                prefix: "scroll-ms-",
+                prefix: Cow::Borrowed("scroll-ms-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1120,7 +1179,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scroll-margin"),
-                prefix: "scroll-me-",
+                prefix: Cow::Borrowed("scroll-me-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1131,7 +1190,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scroll-margin"),
-                prefix: "scroll-mt-",
+                prefix: Cow::Borrowed("scroll-mt-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1142,7 +1201,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scroll-margin"),
-                prefix: "scroll-mr-",
+                prefix: Cow::Borrowed("scroll-mr-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1153,7 +1212,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scroll-margin"),
-                prefix: "scroll-mb-",
+                prefix: Cow::Borrowed("scroll-mb-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1164,7 +1223,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scroll-margin"),
-                prefix: "scroll-ml-",
+                prefix: Cow::Borrowed("scroll-ml-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1176,7 +1235,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== INTERACTIVITY - SCROLL PADDING =====
             TailwindPrefix {
                 order: find_order("scroll-padding"),
-                prefix: "scroll-p-",
+                prefix: Cow::Borrowed("scroll-p-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1187,7 +1246,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scroll-padding"),
-                prefix: "scroll-px-",
+                prefix: Cow::Borrowed("scroll-px-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1198,7 +1257,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scroll-padding"),
-                prefix: "scroll-py-",
+                prefix: Cow::Borrowed("scroll-py-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1209,7 +1268,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scroll-padding"),
-                prefix: "scroll-ps-",
+                prefix: Cow::Borrowed("scroll-ps-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1220,7 +1279,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scroll-padding"),
-                prefix: "scroll-pe-",
+                prefix: Cow::Borrowed("scroll-pe-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1231,7 +1290,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scroll-padding"),
-                prefix: "scroll-pt-",
+                prefix: Cow::Borrowed("scroll-pt-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1242,7 +1301,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scroll-padding"),
-                prefix: "scroll-pr-",
+                prefix: Cow::Borrowed("scroll-pr-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1253,7 +1312,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scroll-padding"),
-                prefix: "scroll-pb-",
+                prefix: Cow::Borrowed("scroll-pb-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1264,7 +1323,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("scroll-padding"),
-                prefix: "scroll-pl-",
+                prefix: Cow::Borrowed("scroll-pl-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1276,14 +1335,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== INTERACTIVITY - SCROLL SNAP ALIGN =====
             TailwindPrefix {
                 order: find_order("scroll-snap-align"),
-                prefix: "snap-",
+                prefix: Cow::Borrowed("snap-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["start", "end", "center"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("scroll-snap-align"),
-                prefix: "snap-align-none",
+                prefix: Cow::Borrowed("snap-align-none"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -1291,7 +1350,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== INTERACTIVITY - SCROLL SNAP STOP =====
             TailwindPrefix {
                 order: find_order("scroll-snap-stop"),
-                prefix: "snap-",
+                prefix: Cow::Borrowed("snap-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["normal", "always"],
                 is_standalone: false,
@@ -1299,7 +1358,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== INTERACTIVITY - SCROLL SNAP TYPE =====
             TailwindPrefix {
                 order: find_order("scroll-snap-type"),
-                prefix: "snap-",
+                prefix: Cow::Borrowed("snap-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["none", "x", "y", "both", "mandatory", "proximity"],
                 is_standalone: false,
@@ -1307,7 +1366,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== INTERACTIVITY - WILL CHANGE =====
             TailwindPrefix {
                 order: find_order("will-change"),
-                prefix: "will-change-",
+                prefix: Cow::Borrowed("will-change-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["auto", "scroll", "contents", "transform"],
                 is_standalone: false,
@@ -1315,7 +1374,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== MISC - ACCENT COLOR =====
             TailwindPrefix {
                 order: find_order("accent-color"),
-                prefix: "accent-",
+                prefix: Cow::Borrowed("accent-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -1328,7 +1387,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== MISC - ASPECT RATIO =====
             TailwindPrefix {
                 order: find_order("aspect-ratio"),
-                prefix: "aspect-",
+                prefix: Cow::Borrowed("aspect-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["auto", "square", "video", "subgrid"],
                 is_standalone: false,
@@ -1336,7 +1395,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== MISC - COLUMNS =====
             TailwindPrefix {
                 order: find_order("columns"),
-                prefix: "columns-",
+                prefix: Cow::Borrowed("columns-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1351,7 +1410,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== MISC - BREAK BEFORE/AFTER/INSIDE =====
             TailwindPrefix {
                 order: find_order("break-before"),
-                prefix: "break-before-",
+                prefix: Cow::Borrowed("break-before-"),
                 value_types: &[ValueType::None],
                 allowed_values: &[
                     "auto",
@@ -1367,7 +1426,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("break-after"),
-                prefix: "break-after-",
+                prefix: Cow::Borrowed("break-after-"),
                 value_types: &[ValueType::None],
                 allowed_values: &[
                     "auto",
@@ -1383,7 +1442,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("break-inside"),
-                prefix: "break-inside-",
+                prefix: Cow::Borrowed("break-inside-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["auto", "avoid", "avoid-page", "avoid-column"],
                 is_standalone: false,
@@ -1391,7 +1450,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== MISC - BOX DECORATION BREAK =====
             TailwindPrefix {
                 order: find_order("box-decoration-break"),
-                prefix: "box-decoration-",
+                prefix: Cow::Borrowed("box-decoration-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["clone", "slice"],
                 is_standalone: false,
@@ -1399,7 +1458,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== MISC - BOX SIZING =====
             TailwindPrefix {
                 order: find_order("box-sizing"),
-                prefix: "box-",
+                prefix: Cow::Borrowed("box-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["border", "content"],
                 is_standalone: false,
@@ -1407,7 +1466,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== MISC - CONTAINER =====
             TailwindPrefix {
                 order: find_order("container-component"),
-                prefix: "container",
+                prefix: Cow::Borrowed("container"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -1415,15 +1474,30 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== MISC - CONTAINER TYPE =====
             TailwindPrefix {
                 order: find_order("container-type"),
-                prefix: "container-type-",
+                prefix: Cow::Borrowed("container-type-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["inline-size", "size", "normal"],
                 is_standalone: false,
             },
+            // ===== MISC - CONTAINER QUERIES =====
+            TailwindPrefix {
+                order: find_order("container-type"),
+                prefix: Cow::Borrowed("@container"),
+                value_types: &[ValueType::None],
+                allowed_values: &[],
+                is_standalone: true,
+            },
+            TailwindPrefix {
+                order: find_order("container-type"),
+                prefix: Cow::Borrowed("@container-"),
+                value_types: &[ValueType::None],
+                allowed_values: &["normal", "size"],
+                is_standalone: false,
+            },
             // ===== MISC - FIELD SIZING =====
             TailwindPrefix {
                 order: find_order("field-sizing"),
-                prefix: "field-sizing-",
+                prefix: Cow::Borrowed("field-sizing-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["fixed", "content"],
                 is_standalone: false,
@@ -1431,7 +1505,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== MISC - ANIMATION =====
             TailwindPrefix {
                 order: find_order("animation"),
-                prefix: "animate-",
+                prefix: Cow::Borrowed("animate-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["spin", "ping", "pulse", "bounce", "none"],
                 is_standalone: false,
@@ -1439,7 +1513,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== MISC - BACKFACE VISIBILITY =====
             TailwindPrefix {
                 order: find_order("transform"),
-                prefix: "backface-",
+                prefix: Cow::Borrowed("backface-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["visible", "hidden"],
                 is_standalone: false,
@@ -1447,7 +1521,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== MISC - COLOR SCHEME =====
             TailwindPrefix {
                 order: find_order("color-scheme"),
-                prefix: "scheme-",
+                prefix: Cow::Borrowed("scheme-"),
                 value_types: &[ValueType::None],
                 allowed_values: &[
                     "normal",
@@ -1462,7 +1536,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== MISC - CONTENT =====
             TailwindPrefix {
                 order: find_order("content"),
-                prefix: "content-",
+                prefix: Cow::Borrowed("content-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["none"],
                 is_standalone: false,
@@ -1470,7 +1544,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== MISC - FORCED COLOR ADJUST =====
             TailwindPrefix {
                 order: find_order("forced-color-adjust"),
-                prefix: "forced-color-adjust-",
+                prefix: Cow::Borrowed("forced-color-adjust-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["auto", "none"],
                 is_standalone: false,
@@ -1478,7 +1552,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== MISC - FILL AND STROKE =====
             TailwindPrefix {
                 order: find_order("fill"),
-                prefix: "fill-",
+                prefix: Cow::Borrowed("fill-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -1497,7 +1571,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("stroke"),
-                prefix: "stroke-",
+                prefix: Cow::Borrowed("stroke-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -1516,7 +1590,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("stroke-width"),
-                prefix: "stroke-",
+                prefix: Cow::Borrowed("stroke-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1528,7 +1602,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - LINE HEIGHT =====
             TailwindPrefix {
                 order: find_order("line-height"),
-                prefix: "leading-",
+                prefix: Cow::Borrowed("leading-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1540,7 +1614,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - LETTER SPACING =====
             TailwindPrefix {
                 order: find_order("letter-spacing"),
-                prefix: "tracking-",
+                prefix: Cow::Borrowed("tracking-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["tighter", "tight", "normal", "wide", "wider", "widest"],
                 is_standalone: false,
@@ -1548,7 +1622,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - TEXT COLOR =====
             TailwindPrefix {
                 order: find_order("color"),
-                prefix: "text-",
+                prefix: Cow::Borrowed("text-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -1561,28 +1635,28 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - TEXT TRANSFORM =====
             TailwindPrefix {
                 order: find_order("text-transform"),
-                prefix: "uppercase",
+                prefix: Cow::Borrowed("uppercase"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("text-transform"),
-                prefix: "lowercase",
+                prefix: Cow::Borrowed("lowercase"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("text-transform"),
-                prefix: "capitalize",
+                prefix: Cow::Borrowed("capitalize"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("text-transform"),
-                prefix: "normal-case",
+                prefix: Cow::Borrowed("normal-case"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -1590,7 +1664,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - TEXT ALIGNMENT =====
             TailwindPrefix {
                 order: find_order("text-align"),
-                prefix: "text-",
+                prefix: Cow::Borrowed("text-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["left", "center", "right", "justify", "start", "end"],
                 is_standalone: false,
@@ -1598,28 +1672,28 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - TEXT DECORATION =====
             TailwindPrefix {
                 order: find_order("text-decoration-line"),
-                prefix: "underline",
+                prefix: Cow::Borrowed("underline"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("text-decoration-line"),
-                prefix: "overline",
+                prefix: Cow::Borrowed("overline"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("text-decoration-line"),
-                prefix: "line-through",
+                prefix: Cow::Borrowed("line-through"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("text-decoration-line"),
-                prefix: "no-underline",
+                prefix: Cow::Borrowed("no-underline"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -1627,7 +1701,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - TEXT DECORATION COLOR =====
             TailwindPrefix {
                 order: find_order("text-decoration-color"),
-                prefix: "decoration-",
+                prefix: Cow::Borrowed("decoration-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -1640,7 +1714,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - TEXT DECORATION STYLE =====
             TailwindPrefix {
                 order: find_order("text-decoration-style"),
-                prefix: "decoration-",
+                prefix: Cow::Borrowed("decoration-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["solid", "double", "dotted", "dashed", "wavy"],
                 is_standalone: false,
@@ -1648,7 +1722,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - TEXT DECORATION THICKNESS =====
             TailwindPrefix {
                 order: find_order("text-decoration-thickness"),
-                prefix: "decoration-",
+                prefix: Cow::Borrowed("decoration-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1660,7 +1734,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - TEXT UNDERLINE OFFSET =====
             TailwindPrefix {
                 order: find_order("text-underline-offset"),
-                prefix: "underline-offset-",
+                prefix: Cow::Borrowed("underline-offset-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1672,7 +1746,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - TEXT INDENT =====
             TailwindPrefix {
                 order: find_order("text-indent"),
-                prefix: "indent-",
+                prefix: Cow::Borrowed("indent-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1684,21 +1758,21 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - TEXT OVERFLOW =====
             TailwindPrefix {
                 order: find_order("text-overflow"),
-                prefix: "truncate",
+                prefix: Cow::Borrowed("truncate"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("text-overflow"),
-                prefix: "text-ellipsis",
+                prefix: Cow::Borrowed("text-ellipsis"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("text-overflow"),
-                prefix: "text-clip",
+                prefix: Cow::Borrowed("text-clip"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -1706,28 +1780,28 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - TEXT WRAP =====
             TailwindPrefix {
                 order: find_order("text-wrap"),
-                prefix: "text-wrap",
+                prefix: Cow::Borrowed("text-wrap"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("text-wrap"),
-                prefix: "text-nowrap",
+                prefix: Cow::Borrowed("text-nowrap"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("text-wrap"),
-                prefix: "text-balance",
+                prefix: Cow::Borrowed("text-balance"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("text-wrap"),
-                prefix: "text-pretty",
+                prefix: Cow::Borrowed("text-pretty"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -1735,7 +1809,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - VERTICAL ALIGNMENT =====
             TailwindPrefix {
                 order: find_order("vertical-align"),
-                prefix: "align-",
+                prefix: Cow::Borrowed("align-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &[
                     "baseline",
@@ -1752,7 +1826,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - WHITESPACE =====
             TailwindPrefix {
                 order: find_order("white-space"),
-                prefix: "whitespace-",
+                prefix: Cow::Borrowed("whitespace-"),
                 value_types: &[ValueType::None],
                 allowed_values: &[
                     "normal",
@@ -1767,7 +1841,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - WORD BREAK =====
             TailwindPrefix {
                 order: find_order("word-break"),
-                prefix: "break-",
+                prefix: Cow::Borrowed("break-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["normal", "words", "all", "keep"],
                 is_standalone: false,
@@ -1775,7 +1849,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - HYPHENS =====
             TailwindPrefix {
                 order: find_order("hyphens"),
-                prefix: "hyphens-",
+                prefix: Cow::Borrowed("hyphens-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["none", "manual", "auto"],
                 is_standalone: false,
@@ -1783,7 +1857,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - LIST STYLE TYPE =====
             TailwindPrefix {
                 order: find_order("list-style-type"),
-                prefix: "list-",
+                prefix: Cow::Borrowed("list-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["none", "disc", "decimal"],
                 is_standalone: false,
@@ -1791,7 +1865,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - LIST STYLE POSITION =====
             TailwindPrefix {
                 order: find_order("list-style-position"),
-                prefix: "list-",
+                prefix: Cow::Borrowed("list-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["inside", "outside"],
                 is_standalone: false,
@@ -1799,7 +1873,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - LIST STYLE IMAGE =====
             TailwindPrefix {
                 order: find_order("list-style-image"),
-                prefix: "list-image-",
+                prefix: Cow::Borrowed("list-image-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["none"],
                 is_standalone: false,
@@ -1807,14 +1881,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - FONT SMOOTHING =====
             TailwindPrefix {
                 order: find_order("-webkit-font-smoothing"),
-                prefix: "antialiased",
+                prefix: Cow::Borrowed("antialiased"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("-webkit-font-smoothing"),
-                prefix: "subpixel-antialiased",
+                prefix: Cow::Borrowed("subpixel-antialiased"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -1822,7 +1896,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - FONT STRETCH =====
             TailwindPrefix {
                 order: find_order("font-stretch"),
-                prefix: "font-stretch-",
+                prefix: Cow::Borrowed("font-stretch-"),
                 value_types: &[
                     ValueType::None,
                     ValueType::CustomProperty,
@@ -1844,7 +1918,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - OPACITY =====
             TailwindPrefix {
                 order: find_order("opacity"),
-                prefix: "opacity-",
+                prefix: Cow::Borrowed("opacity-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1856,7 +1930,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - MIX BLEND MODE =====
             TailwindPrefix {
                 order: find_order("mix-blend-mode"),
-                prefix: "mix-blend-",
+                prefix: Cow::Borrowed("mix-blend-"),
                 value_types: &[ValueType::None],
                 allowed_values: &[
                     "normal",
@@ -1883,7 +1957,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - BOX SHADOW =====
             TailwindPrefix {
                 order: find_order("box-shadow"),
-                prefix: "shadow-",
+                prefix: Cow::Borrowed("shadow-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -1909,7 +1983,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("box-shadow"),
-                prefix: "inset-shadow-",
+                prefix: Cow::Borrowed("inset-shadow-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -1931,7 +2005,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("box-shadow"),
-                prefix: "ring-",
+                prefix: Cow::Borrowed("ring-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Color,
@@ -1944,7 +2018,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("box-shadow"),
-                prefix: "inset-ring-",
+                prefix: Cow::Borrowed("inset-ring-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Color,
@@ -1955,17 +2029,40 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
                 allowed_values: &["inherit", "current", "transparent", "black", "white"],
                 is_standalone: false,
             },
+            TailwindPrefix {
+                order: find_order("ring-offset-width"),
+                prefix: Cow::Borrowed("ring-offset-"),
+                value_types: &[
+                    ValueType::Number,
+                    ValueType::CustomProperty,
+                    ValueType::ArbitraryValue,
+                ],
+                allowed_values: &[],
+                is_standalone: false,
+            },
+            TailwindPrefix {
+                order: find_order("ring-offset-color"),
+                prefix: Cow::Borrowed("ring-offset-"),
+                value_types: &[
+                    ValueType::Color,
+                    ValueType::ColorPalette,
+                    ValueType::CustomProperty,
+                    ValueType::ArbitraryValue,
+                ],
+                allowed_values: &["inherit", "current", "transparent", "black", "white"],
+                is_standalone: false,
+            },
             // ===== EFFECTS - OUTLINE =====
             TailwindPrefix {
                 order: find_order("outline"),
-                prefix: "outline",
+                prefix: Cow::Borrowed("outline"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("outline-width"),
-                prefix: "outline-",
+                prefix: Cow::Borrowed("outline-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -1976,14 +2073,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("outline-style"),
-                prefix: "outline-",
+                prefix: Cow::Borrowed("outline-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["solid", "dashed", "dotted", "double", "none", "hidden"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("outline-color"),
-                prefix: "outline-",
+                prefix: Cow::Borrowed("outline-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -1995,7 +2092,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("outline-offset"),
-                prefix: "outline-offset-",
+                prefix: Cow::Borrowed("outline-offset-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2007,7 +2104,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - FILTER BLUR =====
             TailwindPrefix {
                 order: find_order("filter"),
-                prefix: "blur-",
+                prefix: Cow::Borrowed("blur-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["xs", "sm", "md", "lg", "xl", "2xl", "3xl", "none"],
                 is_standalone: false,
@@ -2015,7 +2112,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - FILTER BRIGHTNESS =====
             TailwindPrefix {
                 order: find_order("filter"),
-                prefix: "brightness-",
+                prefix: Cow::Borrowed("brightness-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2027,7 +2124,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - FILTER CONTRAST =====
             TailwindPrefix {
                 order: find_order("filter"),
-                prefix: "contrast-",
+                prefix: Cow::Borrowed("contrast-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2039,14 +2136,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - FILTER GRAYSCALE =====
             TailwindPrefix {
                 order: find_order("filter"),
-                prefix: "grayscale",
+                prefix: Cow::Borrowed("grayscale"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("filter"),
-                prefix: "grayscale-",
+                prefix: Cow::Borrowed("grayscale-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2058,7 +2155,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - FILTER HUE ROTATE =====
             TailwindPrefix {
                 order: find_order("filter"),
-                prefix: "hue-rotate-",
+                prefix: Cow::Borrowed("hue-rotate-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2070,14 +2167,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== EFFECTS - FILTER INVERT =====
             TailwindPrefix {
                 order: find_order("filter"),
-                prefix: "invert",
+                prefix: Cow::Borrowed("invert"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("filter"),
-                prefix: "invert-",
+                prefix: Cow::Borrowed("invert-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2089,112 +2186,112 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== BORDERS - BORDER RADIUS =====
             TailwindPrefix {
                 order: find_order("border-radius"),
-                prefix: "rounded",
+                prefix: Cow::Borrowed("rounded"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("border-radius"),
-                prefix: "rounded-",
+                prefix: Cow::Borrowed("rounded-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["xs", "sm", "md", "lg", "xl", "2xl", "3xl", "none", "full"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("border-radius"),
-                prefix: "rounded-s-",
+                prefix: Cow::Borrowed("rounded-s-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["xs", "sm", "md", "lg", "xl", "2xl", "3xl", "none", "full"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("border-radius"),
-                prefix: "rounded-e-",
+                prefix: Cow::Borrowed("rounded-e-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["xs", "sm", "md", "lg", "xl", "2xl", "3xl", "none", "full"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("border-radius"),
-                prefix: "rounded-t-",
+                prefix: Cow::Borrowed("rounded-t-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["xs", "sm", "md", "lg", "xl", "2xl", "3xl", "none", "full"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("border-radius"),
-                prefix: "rounded-r-",
+                prefix: Cow::Borrowed("rounded-r-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["xs", "sm", "md", "lg", "xl", "2xl", "3xl", "none", "full"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("border-radius"),
-                prefix: "rounded-b-",
+                prefix: Cow::Borrowed("rounded-b-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["xs", "sm", "md", "lg", "xl", "2xl", "3xl", "none", "full"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("border-radius"),
-                prefix: "rounded-l-",
+                prefix: Cow::Borrowed("rounded-l-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["xs", "sm", "md", "lg", "xl", "2xl", "3xl", "none", "full"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("border-radius"),
-                prefix: "rounded-ss-",
+                prefix: Cow::Borrowed("rounded-ss-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["xs", "sm", "md", "lg", "xl", "2xl", "3xl", "none", "full"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("border-radius"),
-                prefix: "rounded-se-",
+                prefix: Cow::Borrowed("rounded-se-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["xs", "sm", "md", "lg", "xl", "2xl", "3xl", "none", "full"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("border-radius"),
-                prefix: "rounded-ee-",
+                prefix: Cow::Borrowed("rounded-ee-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["xs", "sm", "md", "lg", "xl", "2xl", "3xl", "none", "full"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("border-radius"),
-                prefix: "rounded-es-",
+                prefix: Cow::Borrowed("rounded-es-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["xs", "sm", "md", "lg", "xl", "2xl", "3xl", "none", "full"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("border-radius"),
-                prefix: "rounded-tl-",
+                prefix: Cow::Borrowed("rounded-tl-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["xs", "sm", "md", "lg", "xl", "2xl", "3xl", "none", "full"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("border-radius"),
-                prefix: "rounded-tr-",
+                prefix: Cow::Borrowed("rounded-tr-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["xs", "sm", "md", "lg", "xl", "2xl", "3xl", "none", "full"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("border-radius"),
-                prefix: "rounded-br-",
+                prefix: Cow::Borrowed("rounded-br-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["xs", "sm", "md", "lg", "xl", "2xl", "3xl", "none", "full"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("border-radius"),
-                prefix: "rounded-bl-",
+                prefix: Cow::Borrowed("rounded-bl-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["xs", "sm", "md", "lg", "xl", "2xl", "3xl", "none", "full"],
                 is_standalone: false,
@@ -2202,14 +2299,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== BORDERS - BORDER WIDTH =====
             TailwindPrefix {
                 order: find_order("border-width"),
-                prefix: "border",
+                prefix: Cow::Borrowed("border"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("border-width"),
-                prefix: "border-",
+                prefix: Cow::Borrowed("border-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2220,14 +2317,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-width"),
-                prefix: "border-x",
+                prefix: Cow::Borrowed("border-x"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("border-width"),
-                prefix: "border-x-",
+                prefix: Cow::Borrowed("border-x-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2238,14 +2335,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-width"),
-                prefix: "border-y",
+                prefix: Cow::Borrowed("border-y"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("border-width"),
-                prefix: "border-y-",
+                prefix: Cow::Borrowed("border-y-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2256,14 +2353,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-width"),
-                prefix: "border-s",
+                prefix: Cow::Borrowed("border-s"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("border-width"),
-                prefix: "border-s-",
+                prefix: Cow::Borrowed("border-s-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2274,14 +2371,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-width"),
-                prefix: "border-e",
+                prefix: Cow::Borrowed("border-e"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("border-width"),
-                prefix: "border-e-",
+                prefix: Cow::Borrowed("border-e-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2292,14 +2389,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-width"),
-                prefix: "border-t",
+                prefix: Cow::Borrowed("border-t"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("border-width"),
-                prefix: "border-t-",
+                prefix: Cow::Borrowed("border-t-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2310,14 +2407,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-width"),
-                prefix: "border-r",
+                prefix: Cow::Borrowed("border-r"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("border-width"),
-                prefix: "border-r-",
+                prefix: Cow::Borrowed("border-r-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2328,14 +2425,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-width"),
-                prefix: "border-b",
+                prefix: Cow::Borrowed("border-b"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("border-width"),
-                prefix: "border-b-",
+                prefix: Cow::Borrowed("border-b-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2346,14 +2443,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-width"),
-                prefix: "border-l",
+                prefix: Cow::Borrowed("border-l"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("border-width"),
-                prefix: "border-l-",
+                prefix: Cow::Borrowed("border-l-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2365,14 +2462,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== BORDERS - BORDER STYLE =====
             TailwindPrefix {
                 order: find_order("border-style"),
-                prefix: "border-",
+                prefix: Cow::Borrowed("border-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["solid", "dashed", "dotted", "double", "hidden", "none"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("border-style"),
-                prefix: "divide-",
+                prefix: Cow::Borrowed("divide-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["solid", "dashed", "dotted", "double", "hidden", "none"],
                 is_standalone: false,
@@ -2380,7 +2477,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== BORDERS - BORDER COLOR =====
             TailwindPrefix {
                 order: find_order("border-color"),
-                prefix: "border-",
+                prefix: Cow::Borrowed("border-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -2392,7 +2489,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-color"),
-                prefix: "border-x-",
+                prefix: Cow::Borrowed("border-x-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -2404,7 +2501,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-color"),
-                prefix: "border-y-",
+                prefix: Cow::Borrowed("border-y-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -2416,7 +2513,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-color"),
-                prefix: "border-s-",
+                prefix: Cow::Borrowed("border-s-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -2428,7 +2525,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-color"),
-                prefix: "border-e-",
+                prefix: Cow::Borrowed("border-e-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -2440,7 +2537,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-color"),
-                prefix: "border-t-",
+                prefix: Cow::Borrowed("border-t-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -2452,7 +2549,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-color"),
-                prefix: "border-r-",
+                prefix: Cow::Borrowed("border-r-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -2464,7 +2561,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-color"),
-                prefix: "border-b-",
+                prefix: Cow::Borrowed("border-b-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -2476,7 +2573,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-color"),
-                prefix: "border-l-",
+                prefix: Cow::Borrowed("border-l-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -2488,7 +2585,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("border-color"),
-                prefix: "divide-",
+                prefix: Cow::Borrowed("divide-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -2501,14 +2598,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== BORDERS - DIVIDE WIDTH =====
             TailwindPrefix {
                 order: find_order("divide-x-width"),
-                prefix: "divide-x",
+                prefix: Cow::Borrowed("divide-x"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("divide-x-width"),
-                prefix: "divide-x-",
+                prefix: Cow::Borrowed("divide-x-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2519,14 +2616,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("divide-y-width"),
-                prefix: "divide-y",
+                prefix: Cow::Borrowed("divide-y"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("divide-y-width"),
-                prefix: "divide-y-",
+                prefix: Cow::Borrowed("divide-y-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2537,14 +2634,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("divide-x-width"),
-                prefix: "divide-x-reverse",
+                prefix: Cow::Borrowed("divide-x-reverse"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("divide-y-width"),
-                prefix: "divide-y-reverse",
+                prefix: Cow::Borrowed("divide-y-reverse"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -2552,7 +2649,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== BACKGROUNDS - BACKGROUND COLOR =====
             TailwindPrefix {
                 order: find_order("background-color"),
-                prefix: "bg-",
+                prefix: Cow::Borrowed("bg-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -2565,7 +2662,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== BACKGROUNDS - BACKGROUND ATTACHMENT =====
             TailwindPrefix {
                 order: find_order("background-attachment"),
-                prefix: "bg-",
+                prefix: Cow::Borrowed("bg-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["fixed", "local", "scroll"],
                 is_standalone: false,
@@ -2573,7 +2670,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== BACKGROUNDS - BACKGROUND CLIP =====
             TailwindPrefix {
                 order: find_order("background-clip"),
-                prefix: "bg-clip-",
+                prefix: Cow::Borrowed("bg-clip-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["border", "padding", "content", "text"],
                 is_standalone: false,
@@ -2581,7 +2678,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== BACKGROUNDS - BACKGROUND ORIGIN =====
             TailwindPrefix {
                 order: find_order("background-origin"),
-                prefix: "bg-origin-",
+                prefix: Cow::Borrowed("bg-origin-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["border", "padding", "content"],
                 is_standalone: false,
@@ -2589,7 +2686,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== BACKGROUNDS - BACKGROUND POSITION =====
             TailwindPrefix {
                 order: find_order("background-position"),
-                prefix: "bg-",
+                prefix: Cow::Borrowed("bg-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &[
                     "bottom",
@@ -2607,7 +2704,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== BACKGROUNDS - BACKGROUND REPEAT =====
             TailwindPrefix {
                 order: find_order("background-repeat"),
-                prefix: "bg-",
+                prefix: Cow::Borrowed("bg-"),
                 value_types: &[ValueType::None],
                 allowed_values: &[
                     "repeat",
@@ -2622,7 +2719,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== BACKGROUNDS - BACKGROUND SIZE =====
             TailwindPrefix {
                 order: find_order("background-size"),
-                prefix: "bg-",
+                prefix: Cow::Borrowed("bg-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["auto", "cover", "contain"],
                 is_standalone: false,
@@ -2630,21 +2727,21 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== BACKGROUNDS - BACKGROUND IMAGE =====
             TailwindPrefix {
                 order: find_order("background-image"),
-                prefix: "bg-",
+                prefix: Cow::Borrowed("bg-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["none"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("background-image"),
-                prefix: "bg-gradient-to-",
+                prefix: Cow::Borrowed("bg-gradient-to-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["t", "tr", "r", "br", "b", "bl", "l", "tl"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("background-image"),
-                prefix: "from-",
+                prefix: Cow::Borrowed("from-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -2656,7 +2753,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("background-image"),
-                prefix: "via-",
+                prefix: Cow::Borrowed("via-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -2668,7 +2765,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("background-image"),
-                prefix: "to-",
+                prefix: Cow::Borrowed("to-"),
                 value_types: &[
                     ValueType::Color,
                     ValueType::ColorPalette,
@@ -2681,7 +2778,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== BACKGROUNDS - BACKGROUND BLEND MODE =====
             TailwindPrefix {
                 order: find_order("background-blend-mode"),
-                prefix: "bg-blend-",
+                prefix: Cow::Borrowed("bg-blend-"),
                 value_types: &[ValueType::None],
                 allowed_values: &[
                     "normal",
@@ -2706,7 +2803,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - FONT FAMILY =====
             TailwindPrefix {
                 order: find_order("font-family"),
-                prefix: "font-",
+                prefix: Cow::Borrowed("font-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["sans", "serif", "mono"],
                 is_standalone: false,
@@ -2714,7 +2811,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - FONT SIZE =====
             TailwindPrefix {
                 order: find_order("font-size"),
-                prefix: "text-",
+                prefix: Cow::Borrowed("text-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &[
                     "xs", "sm", "base", "lg", "xl", "2xl", "3xl", "4xl", "5xl", "6xl", "7xl",
@@ -2725,7 +2822,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - FONT WEIGHT =====
             TailwindPrefix {
                 order: find_order("font-weight"),
-                prefix: "font-",
+                prefix: Cow::Borrowed("font-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &[
                     "thin",
@@ -2743,14 +2840,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - FONT STYLE =====
             TailwindPrefix {
                 order: find_order("font-style"),
-                prefix: "italic",
+                prefix: Cow::Borrowed("italic"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("font-style"),
-                prefix: "not-italic",
+                prefix: Cow::Borrowed("not-italic"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -2758,70 +2855,70 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== TYPOGRAPHY - FONT VARIANT NUMERIC =====
             TailwindPrefix {
                 order: find_order("font-variant-numeric"),
-                prefix: "normal-nums",
+                prefix: Cow::Borrowed("normal-nums"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("font-variant-numeric"),
-                prefix: "ordinal",
+                prefix: Cow::Borrowed("ordinal"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("font-variant-numeric"),
-                prefix: "slashed-zero",
+                prefix: Cow::Borrowed("slashed-zero"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("font-variant-numeric"),
-                prefix: "lining-nums",
+                prefix: Cow::Borrowed("lining-nums"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("font-variant-numeric"),
-                prefix: "oldstyle-nums",
+                prefix: Cow::Borrowed("oldstyle-nums"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("font-variant-numeric"),
-                prefix: "proportional-nums",
+                prefix: Cow::Borrowed("proportional-nums"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("font-variant-numeric"),
-                prefix: "tabular-nums",
+                prefix: Cow::Borrowed("tabular-nums"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("font-variant-numeric"),
-                prefix: "diagonal-fractions",
+                prefix: Cow::Borrowed("diagonal-fractions"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("font-variant-numeric"),
-                prefix: "stacked-fractions",
+                prefix: Cow::Borrowed("stacked-fractions"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("isolation"),
-                prefix: "isolation-auto",
+                prefix: Cow::Borrowed("isolation-auto"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -2829,14 +2926,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== LAYOUT - OBJECT FIT & POSITION =====
             TailwindPrefix {
                 order: find_order("object-fit"),
-                prefix: "object-",
+                prefix: Cow::Borrowed("object-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["contain", "cover", "fill", "none", "scale-down"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("object-position"),
-                prefix: "object-",
+                prefix: Cow::Borrowed("object-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &[
                     "bottom",
@@ -2854,21 +2951,21 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== LAYOUT - OVERFLOW =====
             TailwindPrefix {
                 order: find_order("overflow"),
-                prefix: "overflow-",
+                prefix: Cow::Borrowed("overflow-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["auto", "hidden", "clip", "visible", "scroll"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("overflow-x"),
-                prefix: "overflow-x-",
+                prefix: Cow::Borrowed("overflow-x-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["auto", "hidden", "clip", "visible", "scroll"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("overflow-y"),
-                prefix: "overflow-y-",
+                prefix: Cow::Borrowed("overflow-y-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["auto", "hidden", "clip", "visible", "scroll"],
                 is_standalone: false,
@@ -2876,21 +2973,21 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== LAYOUT - OVERSCROLL BEHAVIOR =====
             TailwindPrefix {
                 order: find_order("overscroll-behavior"),
-                prefix: "overscroll-",
+                prefix: Cow::Borrowed("overscroll-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["auto", "contain", "none"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("overscroll-behavior-x"),
-                prefix: "overscroll-x-",
+                prefix: Cow::Borrowed("overscroll-x-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["auto", "contain", "none"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("overscroll-behavior-y"),
-                prefix: "overscroll-y-",
+                prefix: Cow::Borrowed("overscroll-y-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["auto", "contain", "none"],
                 is_standalone: false,
@@ -2898,7 +2995,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== SPACING - PADDING =====
             TailwindPrefix {
                 order: find_order("padding"),
-                prefix: "p-",
+                prefix: Cow::Borrowed("p-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2909,7 +3006,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("padding-inline"),
-                prefix: "px-",
+                prefix: Cow::Borrowed("px-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2920,7 +3017,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("padding-block"),
-                prefix: "py-",
+                prefix: Cow::Borrowed("py-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2931,7 +3028,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("padding-inline-start"),
-                prefix: "ps-",
+                prefix: Cow::Borrowed("ps-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2942,7 +3039,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("padding-inline-end"),
-                prefix: "pe-",
+                prefix: Cow::Borrowed("pe-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2953,7 +3050,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("padding-top"),
-                prefix: "pt-",
+                prefix: Cow::Borrowed("pt-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2964,7 +3061,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("padding-right"),
-                prefix: "pr-",
+                prefix: Cow::Borrowed("pr-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2975,7 +3072,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("padding-bottom"),
-                prefix: "pb-",
+                prefix: Cow::Borrowed("pb-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2986,7 +3083,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("padding-left"),
-                prefix: "pl-",
+                prefix: Cow::Borrowed("pl-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -2998,7 +3095,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== SPACING - MARGIN =====
             TailwindPrefix {
                 order: find_order("margin"),
-                prefix: "m-",
+                prefix: Cow::Borrowed("m-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3009,7 +3106,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("margin-inline"),
-                prefix: "mx-",
+                prefix: Cow::Borrowed("mx-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3020,7 +3117,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("margin-block"),
-                prefix: "my-",
+                prefix: Cow::Borrowed("my-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3031,7 +3128,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("margin-inline-start"),
-                prefix: "ms-",
+                prefix: Cow::Borrowed("ms-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3042,7 +3139,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("margin-inline-end"),
-                prefix: "me-",
+                prefix: Cow::Borrowed("me-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3053,7 +3150,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("margin-top"),
-                prefix: "mt-",
+                prefix: Cow::Borrowed("mt-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3064,7 +3161,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("margin-right"),
-                prefix: "mr-",
+                prefix: Cow::Borrowed("mr-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3075,7 +3172,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("margin-bottom"),
-                prefix: "mb-",
+                prefix: Cow::Borrowed("mb-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3086,7 +3183,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("margin-left"),
-                prefix: "ml-",
+                prefix: Cow::Borrowed("ml-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3098,7 +3195,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== SPACING - SPACE BETWEEN =====
             TailwindPrefix {
                 order: find_order("margin-left") + 1,
-                prefix: "space-x-",
+                prefix: Cow::Borrowed("space-x-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3109,7 +3206,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("margin-left") + 1,
-                prefix: "space-y-",
+                prefix: Cow::Borrowed("space-y-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3120,14 +3217,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("margin-left") + 2,
-                prefix: "space-x-reverse",
+                prefix: Cow::Borrowed("space-x-reverse"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("margin-left") + 2,
-                prefix: "space-y-reverse",
+                prefix: Cow::Borrowed("space-y-reverse"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -3135,7 +3232,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== SIZING - WIDTH =====
             TailwindPrefix {
                 order: find_order("width"),
-                prefix: "w-",
+                prefix: Cow::Borrowed("w-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -3152,7 +3249,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== SIZING - MIN-WIDTH =====
             TailwindPrefix {
                 order: find_order("min-width"),
-                prefix: "min-w-",
+                prefix: Cow::Borrowed("min-w-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -3169,7 +3266,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== SIZING - MAX-WIDTH =====
             TailwindPrefix {
                 order: find_order("max-width"),
-                prefix: "max-w-",
+                prefix: Cow::Borrowed("max-w-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -3186,7 +3283,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== SIZING - HEIGHT =====
             TailwindPrefix {
                 order: find_order("height"),
-                prefix: "h-",
+                prefix: Cow::Borrowed("h-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -3202,7 +3299,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== SIZING - MIN-HEIGHT =====
             TailwindPrefix {
                 order: find_order("min-height"),
-                prefix: "min-h-",
+                prefix: Cow::Borrowed("min-h-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -3218,7 +3315,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== SIZING - MAX-HEIGHT =====
             TailwindPrefix {
                 order: find_order("max-height"),
-                prefix: "max-h-",
+                prefix: Cow::Borrowed("max-h-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -3234,7 +3331,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== SIZING - SIZE (COMBINED WIDTH/HEIGHT) =====
             TailwindPrefix {
                 order: find_order("width"),
-                prefix: "size-",
+                prefix: Cow::Borrowed("size-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -3250,28 +3347,28 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== FLEXBOX - FLEX DIRECTION =====
             TailwindPrefix {
                 order: find_order("flex-direction"),
-                prefix: "flex-row",
+                prefix: Cow::Borrowed("flex-row"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("flex-direction"),
-                prefix: "flex-row-reverse",
+                prefix: Cow::Borrowed("flex-row-reverse"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("flex-direction"),
-                prefix: "flex-col",
+                prefix: Cow::Borrowed("flex-col"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("flex-direction"),
-                prefix: "flex-col-reverse",
+                prefix: Cow::Borrowed("flex-col-reverse"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -3279,21 +3376,21 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== FLEXBOX - FLEX WRAP =====
             TailwindPrefix {
                 order: find_order("flex-wrap"),
-                prefix: "flex-wrap",
+                prefix: Cow::Borrowed("flex-wrap"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("flex-wrap"),
-                prefix: "flex-wrap-reverse",
+                prefix: Cow::Borrowed("flex-wrap-reverse"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("flex-wrap"),
-                prefix: "flex-nowrap",
+                prefix: Cow::Borrowed("flex-nowrap"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -3301,7 +3398,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== FLEXBOX - FLEX =====
             TailwindPrefix {
                 order: find_order("flex"),
-                prefix: "flex-",
+                prefix: Cow::Borrowed("flex-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -3314,14 +3411,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== FLEXBOX - FLEX GROW =====
             TailwindPrefix {
                 order: find_order("flex-grow"),
-                prefix: "grow",
+                prefix: Cow::Borrowed("grow"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("flex-grow"),
-                prefix: "grow-",
+                prefix: Cow::Borrowed("grow-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3333,14 +3430,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== FLEXBOX - FLEX SHRINK =====
             TailwindPrefix {
                 order: find_order("flex-shrink"),
-                prefix: "shrink",
+                prefix: Cow::Borrowed("shrink"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("flex-shrink"),
-                prefix: "shrink-",
+                prefix: Cow::Borrowed("shrink-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3352,7 +3449,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== FLEXBOX - FLEX BASIS =====
             TailwindPrefix {
                 order: find_order("flex-basis"),
-                prefix: "basis-",
+                prefix: Cow::Borrowed("basis-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -3368,7 +3465,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== FLEXBOX - JUSTIFY CONTENT =====
             TailwindPrefix {
                 order: find_order("justify-content"),
-                prefix: "justify-",
+                prefix: Cow::Borrowed("justify-"),
                 value_types: &[ValueType::None],
                 allowed_values: &[
                     "start", "end", "center", "between", "around", "evenly", "stretch", "baseline",
@@ -3379,7 +3476,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== FLEXBOX - JUSTIFY ITEMS =====
             TailwindPrefix {
                 order: find_order("justify-items"),
-                prefix: "justify-items-",
+                prefix: Cow::Borrowed("justify-items-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["start", "end", "center", "stretch", "normal"],
                 is_standalone: false,
@@ -3387,7 +3484,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== FLEXBOX - JUSTIFY SELF =====
             TailwindPrefix {
                 order: find_order("justify-self"),
-                prefix: "justify-self-",
+                prefix: Cow::Borrowed("justify-self-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["auto", "start", "end", "center", "stretch"],
                 is_standalone: false,
@@ -3395,7 +3492,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== FLEXBOX - ALIGN CONTENT =====
             TailwindPrefix {
                 order: find_order("align-content"),
-                prefix: "content-",
+                prefix: Cow::Borrowed("content-"),
                 value_types: &[ValueType::None],
                 allowed_values: &[
                     "normal", "center", "start", "end", "between", "around", "evenly", "baseline",
@@ -3406,7 +3503,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== FLEXBOX - ALIGN ITEMS =====
             TailwindPrefix {
                 order: find_order("align-items"),
-                prefix: "items-",
+                prefix: Cow::Borrowed("items-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["start", "end", "center", "baseline", "stretch"],
                 is_standalone: false,
@@ -3414,7 +3511,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== FLEXBOX - ALIGN SELF =====
             TailwindPrefix {
                 order: find_order("align-self"),
-                prefix: "self-",
+                prefix: Cow::Borrowed("self-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["auto", "start", "end", "center", "stretch", "baseline"],
                 is_standalone: false,
@@ -3422,7 +3519,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== FLEXBOX - PLACE CONTENT =====
             TailwindPrefix {
                 order: find_order("place-content"),
-                prefix: "place-content-",
+                prefix: Cow::Borrowed("place-content-"),
                 value_types: &[ValueType::None],
                 allowed_values: &[
                     "center", "start", "end", "between", "around", "evenly", "baseline", "stretch",
@@ -3432,7 +3529,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== FLEXBOX - PLACE ITEMS =====
             TailwindPrefix {
                 order: find_order("place-items"),
-                prefix: "place-items-",
+                prefix: Cow::Borrowed("place-items-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["start", "end", "center", "baseline", "stretch"],
                 is_standalone: false,
@@ -3440,7 +3537,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== FLEXBOX - PLACE SELF =====
             TailwindPrefix {
                 order: find_order("place-self"),
-                prefix: "place-self-",
+                prefix: Cow::Borrowed("place-self-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["auto", "start", "end", "center", "stretch"],
                 is_standalone: false,
@@ -3448,7 +3545,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== GRID - GRID TEMPLATE COLUMNS =====
             TailwindPrefix {
                 order: find_order("grid-template-columns"),
-                prefix: "grid-cols-",
+                prefix: Cow::Borrowed("grid-cols-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3460,7 +3557,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== GRID - GRID TEMPLATE ROWS =====
             TailwindPrefix {
                 order: find_order("grid-template-rows"),
-                prefix: "grid-rows-",
+                prefix: Cow::Borrowed("grid-rows-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3472,14 +3569,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== GRID - GRID COLUMN =====
             TailwindPrefix {
                 order: find_order("grid-column"),
-                prefix: "col-",
+                prefix: Cow::Borrowed("col-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["auto"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("grid-column"),
-                prefix: "col-span-",
+                prefix: Cow::Borrowed("col-span-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3490,7 +3587,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("grid-column-start"),
-                prefix: "col-start-",
+                prefix: Cow::Borrowed("col-start-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3501,7 +3598,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("grid-column-end"),
-                prefix: "col-end-",
+                prefix: Cow::Borrowed("col-end-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3513,14 +3610,14 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== GRID - GRID ROW =====
             TailwindPrefix {
                 order: find_order("grid-row"),
-                prefix: "row-",
+                prefix: Cow::Borrowed("row-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["auto"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("grid-row"),
-                prefix: "row-span-",
+                prefix: Cow::Borrowed("row-span-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3531,7 +3628,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("grid-row-start"),
-                prefix: "row-start-",
+                prefix: Cow::Borrowed("row-start-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3542,7 +3639,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("grid-row-end"),
-                prefix: "row-end-",
+                prefix: Cow::Borrowed("row-end-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3554,7 +3651,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== GRID - GRID AUTO FLOW =====
             TailwindPrefix {
                 order: find_order("grid-auto-flow"),
-                prefix: "grid-flow-",
+                prefix: Cow::Borrowed("grid-flow-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["row", "col", "dense", "row-dense", "col-dense"],
                 is_standalone: false,
@@ -3562,7 +3659,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== GRID - GRID AUTO COLUMNS =====
             TailwindPrefix {
                 order: find_order("grid-auto-columns"),
-                prefix: "auto-cols-",
+                prefix: Cow::Borrowed("auto-cols-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["auto", "min", "max", "fr"],
                 is_standalone: false,
@@ -3570,7 +3667,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== GRID - GRID AUTO ROWS =====
             TailwindPrefix {
                 order: find_order("grid-auto-rows"),
-                prefix: "auto-rows-",
+                prefix: Cow::Borrowed("auto-rows-"),
                 value_types: &[ValueType::CustomProperty, ValueType::ArbitraryValue],
                 allowed_values: &["auto", "min", "max", "fr"],
                 is_standalone: false,
@@ -3578,7 +3675,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== SPACING - GAP =====
             TailwindPrefix {
                 order: find_order("gap"),
-                prefix: "gap-",
+                prefix: Cow::Borrowed("gap-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3589,7 +3686,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("column-gap"),
-                prefix: "gap-x-",
+                prefix: Cow::Borrowed("gap-x-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3600,7 +3697,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("row-gap"),
-                prefix: "gap-y-",
+                prefix: Cow::Borrowed("gap-y-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3611,147 +3708,147 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "block",
+                prefix: Cow::Borrowed("block"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "inline-block",
+                prefix: Cow::Borrowed("inline-block"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "inline",
+                prefix: Cow::Borrowed("inline"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "flex",
+                prefix: Cow::Borrowed("flex"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "inline-flex",
+                prefix: Cow::Borrowed("inline-flex"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "table",
+                prefix: Cow::Borrowed("table"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "inline-table",
+                prefix: Cow::Borrowed("inline-table"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "table-caption",
+                prefix: Cow::Borrowed("table-caption"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "table-cell",
+                prefix: Cow::Borrowed("table-cell"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "table-column",
+                prefix: Cow::Borrowed("table-column"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "table-column-group",
+                prefix: Cow::Borrowed("table-column-group"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "table-footer-group",
+                prefix: Cow::Borrowed("table-footer-group"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "table-header-group",
+                prefix: Cow::Borrowed("table-header-group"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "table-row-group",
+                prefix: Cow::Borrowed("table-row-group"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "table-row",
+                prefix: Cow::Borrowed("table-row"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "flow-root",
+                prefix: Cow::Borrowed("flow-root"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "grid",
+                prefix: Cow::Borrowed("grid"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "inline-grid",
+                prefix: Cow::Borrowed("inline-grid"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "contents",
+                prefix: Cow::Borrowed("contents"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "list-item",
+                prefix: Cow::Borrowed("list-item"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("display"),
-                prefix: "hidden",
+                prefix: Cow::Borrowed("hidden"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -3759,21 +3856,21 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== LAYOUT - VISIBILITY UTILITIES =====
             TailwindPrefix {
                 order: find_order("visibility"),
-                prefix: "visible",
+                prefix: Cow::Borrowed("visible"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("visibility"),
-                prefix: "invisible",
+                prefix: Cow::Borrowed("invisible"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("visibility"),
-                prefix: "collapse",
+                prefix: Cow::Borrowed("collapse"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -3781,35 +3878,35 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== LAYOUT - POSITION UTILITIES =====
             TailwindPrefix {
                 order: find_order("position"),
-                prefix: "static",
+                prefix: Cow::Borrowed("static"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("position"),
-                prefix: "fixed",
+                prefix: Cow::Borrowed("fixed"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("position"),
-                prefix: "absolute",
+                prefix: Cow::Borrowed("absolute"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("position"),
-                prefix: "relative",
+                prefix: Cow::Borrowed("relative"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
             },
             TailwindPrefix {
                 order: find_order("position"),
-                prefix: "sticky",
+                prefix: Cow::Borrowed("sticky"),
                 value_types: &[ValueType::None],
                 allowed_values: &[],
                 is_standalone: true,
@@ -3818,7 +3915,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // Inset utilities
             TailwindPrefix {
                 order: find_order("inset"),
-                prefix: "inset-",
+                prefix: Cow::Borrowed("inset-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -3830,7 +3927,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("inset-inline"),
-                prefix: "inset-x-",
+                prefix: Cow::Borrowed("inset-x-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -3842,7 +3939,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("inset-block"),
-                prefix: "inset-y-",
+                prefix: Cow::Borrowed("inset-y-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -3854,7 +3951,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("inset-inline-start"),
-                prefix: "start-",
+                prefix: Cow::Borrowed("start-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -3866,7 +3963,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("inset-inline-end"),
-                prefix: "end-",
+                prefix: Cow::Borrowed("end-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -3878,7 +3975,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("top"),
-                prefix: "top-",
+                prefix: Cow::Borrowed("top-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -3890,7 +3987,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("right"),
-                prefix: "right-",
+                prefix: Cow::Borrowed("right-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -3902,7 +3999,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("bottom"),
-                prefix: "bottom-",
+                prefix: Cow::Borrowed("bottom-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -3914,7 +4011,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             },
             TailwindPrefix {
                 order: find_order("left"),
-                prefix: "left-",
+                prefix: Cow::Borrowed("left-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::Fraction,
@@ -3927,7 +4024,7 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== LAYOUT - Z-INDEX UTILITIES =====
             TailwindPrefix {
                 order: find_order("z-index"),
-                prefix: "z-",
+                prefix: Cow::Borrowed("z-"),
                 value_types: &[
                     ValueType::Number,
                     ValueType::CustomProperty,
@@ -3939,18 +4036,18 @@ pub fn get_tailwind_prefixes() -> &'static [TailwindPrefix] {
             // ===== LAYOUT - FLOATS AND CLEAR =====
             TailwindPrefix {
                 order: find_order("float"),
-                prefix: "float-",
+                prefix: Cow::Borrowed("float-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["right", "left", "start", "end", "none"],
                 is_standalone: false,
             },
             TailwindPrefix {
                 order: find_order("clear"),
-                prefix: "clear-",
+                prefix: Cow::Borrowed("clear-"),
                 value_types: &[ValueType::None],
                 allowed_values: &["left", "right", "both", "start", "end", "none"],
                 is_standalone: false,
             },
-        ]
+        ])
     })
 }
@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+
+/// A half-open `[start, end)` range of document offsets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct TextRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl TextRange {
+    /// Check whether a `[start, end)` span overlaps this range.
+    ///
+    /// An empty range (a cursor position) intersects the span it is inside of.
+    pub fn intersects(&self, start: usize, end: usize) -> bool {
+        if self.start == self.end {
+            return start <= self.start && self.start <= end;
+        }
+
+        start < self.end && self.start < end
+    }
+
+    /// Convert a range given in UTF-16 code units into byte offsets
+    pub fn to_byte_offsets(&self, document: &str) -> TextRange {
+        TextRange {
+            start: utf16_to_byte_offset(document, self.start),
+            end: utf16_to_byte_offset(document, self.end),
+        }
+    }
+}
+
+/// A replacement of the document text in `[start, end)`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TextEdit {
+    pub start: usize,
+    pub end: usize,
+    pub replacement: String,
+}
+
+impl TextEdit {
+    /// Convert an edit given in byte offsets into UTF-16 code units
+    pub fn to_utf16_offsets(&self, document: &str) -> TextEdit {
+        TextEdit {
+            start: byte_to_utf16_offset(document, self.start),
+            end: byte_to_utf16_offset(document, self.end),
+            replacement: self.replacement.clone(),
+        }
+    }
+}
+
+/// Convert a byte offset into a UTF-16 code unit offset, clamped to the document length
+pub fn byte_to_utf16_offset(document: &str, offset: usize) -> usize {
+    document
+        .char_indices()
+        .take_while(|&(byte_offset, _)| byte_offset < offset)
+        .map(|(_, c)| c.len_utf16())
+        .sum()
+}
+
+/// Convert a UTF-16 code unit offset into a byte offset, clamped to the document length
+pub fn utf16_to_byte_offset(document: &str, offset: usize) -> usize {
+    let mut utf16_offset = 0;
+
+    for (byte_offset, c) in document.char_indices() {
+        if utf16_offset >= offset {
+            return byte_offset;
+        }
+        utf16_offset += c.len_utf16();
+    }
+
+    document.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{class_edits_in_ranges, sort_classes_in_ranges};
+
+    #[test]
+    fn test_utf16_to_byte_offset() {
+        let document = "é😀a";
+        assert_eq!(utf16_to_byte_offset(document, 0), 0);
+        assert_eq!(utf16_to_byte_offset(document, 1), 2);
+        assert_eq!(utf16_to_byte_offset(document, 3), 6);
+        assert_eq!(utf16_to_byte_offset(document, 4), 7);
+        assert_eq!(utf16_to_byte_offset(document, 100), 7);
+
+        assert_eq!(byte_to_utf16_offset(document, 2), 1);
+        assert_eq!(byte_to_utf16_offset(document, 6), 3);
+        assert_eq!(byte_to_utf16_offset(document, 100), 4);
+    }
+
+    #[test]
+    fn test_intersects() {
+        let range = TextRange { start: 10, end: 20 };
+        assert!(range.intersects(5, 11));
+        assert!(range.intersects(19, 30));
+        assert!(!range.intersects(0, 10));
+        assert!(!range.intersects(20, 25));
+
+        let cursor = TextRange { start: 10, end: 10 };
+        assert!(cursor.intersects(5, 15));
+        assert!(!cursor.intersects(11, 15));
+    }
+
+    #[test]
+    fn test_sort_only_intersecting_class_strings() {
+        let document = "const a = \"p-4 flex mt-2\";\nconst b = \"p-4 flex mt-2\";\n";
+        let second_line = document.find("const b").unwrap();
+        let range = TextRange {
+            start: second_line,
+            end: document.len(),
+        };
+
+        assert_eq!(
+            sort_classes_in_ranges(document, "ts", &[range]),
+            "const a = \"p-4 flex mt-2\";\nconst b = \"mt-2 flex p-4\";\n"
+        );
+    }
+
+    #[test]
+    fn test_edits_cover_only_intersecting_class_strings() {
+        let document = "const a = \"p-4 flex mt-2\";\nconst b = \"p-4 flex mt-2\";\n";
+        let second_line = document.find("const b").unwrap();
+        let range = TextRange {
+            start: second_line,
+            end: document.len(),
+        };
+
+        let edits = class_edits_in_ranges(document, "ts", &[range]);
+
+        assert_eq!(
+            edits,
+            vec![TextEdit {
+                start: second_line + 10,
+                end: second_line + 25,
+                replacement: "\"mt-2 flex p-4\"".to_string(),
+            }]
+        );
+    }
+}
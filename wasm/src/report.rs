@@ -0,0 +1,345 @@
+use serde::Serialize;
+use serde_json::json;
+
+use crate::check::{check_classes, line_column};
+use crate::extractor::ExtractError;
+
+/// Rule id reported for every unsorted class string
+pub const UNSORTED_CLASSES_RULE_ID: &str = "tailwind-sort/unsorted-classes";
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const TOOL_NAME: &str = "tailwind-sort";
+const TOOL_INFORMATION_URI: &str =
+    "https://github.com/Digital-Magistery-Software/tailwind-class-sorter";
+/// Base id that relative artifact URIs are resolved against
+const SRCROOT_BASE_ID: &str = "%SRCROOT%";
+
+/// An unsorted class string, ready to be emitted as JSON or SARIF
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Finding {
+    pub rule_id: &'static str,
+    /// The document's path as given, or its URI when it came from an editor
+    pub path: String,
+    pub region: Region,
+    /// The class string as it appears in the document
+    pub original: String,
+    pub fix: Fix,
+}
+
+/// A one-based line/column span of a document (columns count characters)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Region {
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    /// Byte offset of the start of the region
+    pub byte_offset: usize,
+    /// Length of the region in bytes
+    pub byte_length: usize,
+}
+
+/// The replacement that sorts a class string
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Fix {
+    pub description: String,
+    /// The region to replace (the class string without its quotes)
+    pub region: Region,
+    pub replacement: String,
+}
+
+/// Output format for a findings report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Json,
+    Sarif,
+}
+
+impl ReportFormat {
+    pub fn from_name(name: &str) -> Option<ReportFormat> {
+        match name {
+            "json" => Some(ReportFormat::Json),
+            "sarif" => Some(ReportFormat::Sarif),
+            _ => None,
+        }
+    }
+}
+
+/// Collect findings for every unsorted class string in the document at `path`
+pub fn find_unsorted(
+    document: &str,
+    file_extension: &str,
+    path: &str,
+) -> Result<Vec<Finding>, ExtractError> {
+    let findings = check_classes(document, file_extension)?
+        .into_iter()
+        .map(|unsorted| {
+            // Fixes replace only the contents of the literal, keeping its quotes
+            let literal = &document[unsorted.start..unsorted.end];
+            let quoted = literal.len() >= 2
+                && ['"', '\'', '`']
+                    .iter()
+                    .any(|&q| literal.starts_with(q) && literal.ends_with(q));
+            let (fix_start, fix_end) = if quoted {
+                (unsorted.start + 1, unsorted.end - 1)
+            } else {
+                (unsorted.start, unsorted.end)
+            };
+
+            Finding {
+                rule_id: UNSORTED_CLASSES_RULE_ID,
+                path: path.to_string(),
+                region: region(document, unsorted.start, unsorted.end),
+                original: unsorted.original,
+                fix: Fix {
+                    description: "Sort Tailwind classes".to_string(),
+                    region: region(document, fix_start, fix_end),
+                    replacement: unsorted.expected,
+                },
+            }
+        })
+        .collect();
+
+    Ok(findings)
+}
+
+fn region(document: &str, start: usize, end: usize) -> Region {
+    let (start_line, start_column) = line_column(document, start);
+    let (end_line, end_column) = line_column(document, end);
+
+    Region {
+        start_line,
+        start_column,
+        end_line,
+        end_column,
+        byte_offset: start,
+        byte_length: end - start,
+    }
+}
+
+/// Render findings in the requested format
+pub fn render_report(findings: &[Finding], format: ReportFormat) -> String {
+    match format {
+        ReportFormat::Json => json_report(findings),
+        ReportFormat::Sarif => sarif_report(findings),
+    }
+}
+
+/// Render findings as a JSON array
+pub fn json_report(findings: &[Finding]) -> String {
+    serde_json::to_string_pretty(findings).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// Render findings as a SARIF 2.1.0 log with a single run
+pub fn sarif_report(findings: &[Finding]) -> String {
+    let sarif_region = |region: &Region| {
+        json!({
+            "startLine": region.start_line,
+            "startColumn": region.start_column,
+            "endLine": region.end_line,
+            "endColumn": region.end_column,
+            "byteOffset": region.byte_offset,
+            "byteLength": region.byte_length,
+        })
+    };
+
+    let results: Vec<serde_json::Value> = findings
+        .iter()
+        .map(|finding| {
+            json!({
+                "ruleId": finding.rule_id,
+                "level": "warning",
+                "message": {
+                    "text": format!(
+                        "Tailwind classes are not sorted. Expected \"{}\".",
+                        finding.fix.replacement
+                    ),
+                },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": sarif_artifact_location(&finding.path),
+                        "region": sarif_region(&finding.region),
+                    },
+                }],
+                "fixes": [{
+                    "description": { "text": finding.fix.description },
+                    "artifactChanges": [{
+                        "artifactLocation": sarif_artifact_location(&finding.path),
+                        "replacements": [{
+                            "deletedRegion": sarif_region(&finding.fix.region),
+                            "insertedContent": { "text": finding.fix.replacement },
+                        }],
+                    }],
+                }],
+            })
+        })
+        .collect();
+
+    let log = json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": TOOL_NAME,
+                    "informationUri": TOOL_INFORMATION_URI,
+                    "rules": [{
+                        "id": UNSORTED_CLASSES_RULE_ID,
+                        "shortDescription": { "text": "Tailwind classes are not sorted" },
+                        "defaultConfiguration": { "level": "warning" },
+                    }],
+                },
+            },
+            "columnKind": "unicodeCodePoints",
+            "results": results,
+        }],
+    });
+
+    serde_json::to_string_pretty(&log).unwrap_or_default()
+}
+
+/// Turn a file path or URI into a SARIF artifact location.
+///
+/// URIs are kept as they are, absolute paths become `file://` URIs and relative paths are
+/// percent-encoded and resolved against `%SRCROOT%`.
+fn sarif_artifact_location(path: &str) -> serde_json::Value {
+    if has_uri_scheme(path) {
+        return json!({ "uri": path });
+    }
+
+    let path = path.replace('\\', "/");
+    let bytes = path.as_bytes();
+
+    if bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' {
+        // Windows drive path, e.g. `C:/src/a.ts`
+        return json!({
+            "uri": format!("file:///{}:{}", &path[..1], percent_encode_path(&path[2..])),
+        });
+    }
+
+    if path.starts_with('/') {
+        return json!({ "uri": format!("file://{}", percent_encode_path(&path)) });
+    }
+
+    let relative = path.trim_start_matches("./");
+    json!({
+        "uri": percent_encode_path(relative),
+        "uriBaseId": SRCROOT_BASE_ID,
+    })
+}
+
+/// Whether the text starts with a URI scheme such as `file:` (a drive letter is not one)
+fn has_uri_scheme(uri: &str) -> bool {
+    let Some((scheme, _)) = uri.split_once(':') else {
+        return false;
+    };
+
+    scheme.len() > 1
+        && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+/// Percent-encode everything in a path except unreserved characters and `/`
+fn percent_encode_path(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~' | b'/') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str = "const a = \"mt-2 flex p-4\";\nconst b = \"p-4 flex mt-2\";\n";
+
+    #[test]
+    fn test_find_unsorted_regions() {
+        let findings = find_unsorted(DOCUMENT, "ts", "src/a.ts").unwrap();
+
+        assert_eq!(findings.len(), 1);
+        let finding = &findings[0];
+        assert_eq!(finding.rule_id, UNSORTED_CLASSES_RULE_ID);
+        assert_eq!(finding.original, "p-4 flex mt-2");
+        assert_eq!(
+            (
+                finding.region.start_line,
+                finding.region.start_column,
+                finding.region.end_column
+            ),
+            (2, 11, 26)
+        );
+        assert_eq!(
+            (
+                finding.fix.region.start_column,
+                finding.fix.region.end_column
+            ),
+            (12, 25)
+        );
+        assert_eq!(finding.fix.replacement, "mt-2 flex p-4");
+    }
+
+    #[test]
+    fn test_json_report() {
+        let report: serde_json::Value = serde_json::from_str(&json_report(
+            &find_unsorted(DOCUMENT, "ts", "src/a.ts").unwrap(),
+        ))
+        .unwrap();
+
+        assert_eq!(report[0]["ruleId"], UNSORTED_CLASSES_RULE_ID);
+        assert_eq!(report[0]["path"], "src/a.ts");
+        assert_eq!(report[0]["region"]["startLine"], 2);
+        assert_eq!(report[0]["fix"]["replacement"], "mt-2 flex p-4");
+    }
+
+    #[test]
+    fn test_sarif_report() {
+        let report: serde_json::Value = serde_json::from_str(&sarif_report(
+            &find_unsorted(DOCUMENT, "ts", "src/a.ts").unwrap(),
+        ))
+        .unwrap();
+
+        assert_eq!(report["version"], "2.1.0");
+        let result = &report["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], UNSORTED_CLASSES_RULE_ID);
+        let location = &result["locations"][0]["physicalLocation"]["artifactLocation"];
+        assert_eq!(location["uri"], "src/a.ts");
+        assert_eq!(location["uriBaseId"], SRCROOT_BASE_ID);
+        assert_eq!(
+            result["fixes"][0]["artifactChanges"][0]["replacements"][0]["insertedContent"]["text"],
+            "mt-2 flex p-4"
+        );
+    }
+
+    #[test]
+    fn test_sarif_artifact_locations() {
+        assert_eq!(
+            sarif_artifact_location("./src/my page#1.tsx"),
+            json!({ "uri": "src/my%20page%231.tsx", "uriBaseId": SRCROOT_BASE_ID })
+        );
+        assert_eq!(
+            sarif_artifact_location("/home/me/app/é.ts"),
+            json!({ "uri": "file:///home/me/app/%C3%A9.ts" })
+        );
+        assert_eq!(
+            sarif_artifact_location("C:\\app\\src\\a.ts"),
+            json!({ "uri": "file:///C:/app/src/a.ts" })
+        );
+        assert_eq!(
+            sarif_artifact_location("file:///project/a.ts"),
+            json!({ "uri": "file:///project/a.ts" })
+        );
+    }
+}
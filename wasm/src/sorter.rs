@@ -1,13 +1,76 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 use std::sync::Mutex;
 use std::sync::OnceLock;
 
-use crate::debug_log;
+use crate::colors::{COLOR_KEYWORDS, is_color};
 use crate::prefixes::{TailwindPrefix, ValueType, find_order, get_tailwind_prefixes};
+use crate::theme::{
+    is_theme_font_size, is_theme_spacing, replaces_default_font_sizes, replaces_default_spacing,
+    uses_spacing_scale,
+};
+use crate::variants::{compare_variant_chains, highest_variant, sorts_after_breakpoints};
+use crate::{debug_log, trace_log};
 
 static REMOVE_DUPLICATES: OnceLock<Mutex<bool>> = OnceLock::new();
-static DEBUG_MODE: OnceLock<Mutex<bool>> = OnceLock::new();
 static NORMALIZE_WHITESPACE: OnceLock<Mutex<bool>> = OnceLock::new();
+static PRINT_WIDTH: OnceLock<Mutex<usize>> = OnceLock::new();
+static OUTPUT_MODE: OnceLock<Mutex<OutputMode>> = OnceLock::new();
+static CLASS_PREFIX: OnceLock<Mutex<String>> = OnceLock::new();
+static VARIANT_SEPARATOR: OnceLock<Mutex<String>> = OnceLock::new();
+
+/// How sorted classes are laid out when written back to the document
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputMode {
+    /// Single spaces between all classes
+    Flat,
+    /// Double spaces between category groups
+    Spaced,
+    /// One line per category group where the literal allows newlines
+    Lines,
+    /// Like `Lines`, but with one line per variant prefix (`hover:`, `md:`, ...)
+    VariantLines,
+}
+
+impl OutputMode {
+    /// Parse an output mode name, as passed in from the extension settings
+    pub fn from_name(name: &str) -> Option<OutputMode> {
+        match name {
+            "flat" => Some(OutputMode::Flat),
+            "spaced" => Some(OutputMode::Spaced),
+            "lines" => Some(OutputMode::Lines),
+            "variant-lines" => Some(OutputMode::VariantLines),
+            _ => None,
+        }
+    }
+}
+
+/// The buckets `sort_tailwind_classes` partitions classes into, in output order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClassCategory {
+    Custom,
+    Arbitrary,
+    Container,
+    Parasite,
+    Base,
+    StateVariant,
+    ResponsiveVariant,
+    UnknownArbitrary,
+}
+
+impl ClassCategory {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ClassCategory::Custom => "custom",
+            ClassCategory::Arbitrary => "arbitrary",
+            ClassCategory::Container => "container",
+            ClassCategory::Parasite => "parasite",
+            ClassCategory::Base => "base",
+            ClassCategory::StateVariant => "state-variant",
+            ClassCategory::ResponsiveVariant => "responsive-variant",
+            ClassCategory::UnknownArbitrary => "unknown-arbitrary",
+        }
+    }
+}
 
 #[derive(Debug)]
 enum TemplateChunk {
@@ -22,30 +85,65 @@ pub fn set_remove_duplicates(remove: bool) {
     }
 }
 
-pub fn set_debug_mode(debug: bool) {
-    let mutex = DEBUG_MODE.get_or_init(|| Mutex::new(false));
+pub fn set_normalize_whitespace(normalize: bool) {
+    let mutex = NORMALIZE_WHITESPACE.get_or_init(|| Mutex::new(true));
     if let Ok(mut value) = mutex.lock() {
-        *value = debug;
+        *value = normalize;
     }
 }
 
-pub fn set_normalize_whitespace(normalize: bool) {
-    let mutex = NORMALIZE_WHITESPACE.get_or_init(|| Mutex::new(true));
+/// Set the column limit used to wrap long class lists (0 disables wrapping)
+pub fn set_print_width(width: usize) {
+    let mutex = PRINT_WIDTH.get_or_init(|| Mutex::new(0));
     if let Ok(mut value) = mutex.lock() {
-        *value = normalize;
+        *value = width;
     }
 }
 
-pub fn is_debug_enabled() -> bool {
-    let mutex = DEBUG_MODE.get_or_init(|| Mutex::new(false));
-    mutex.lock().map(|guard| *guard).unwrap_or(false)
+pub fn set_output_mode(mode: OutputMode) {
+    let mutex = OUTPUT_MODE.get_or_init(|| Mutex::new(OutputMode::Flat));
+    if let Ok(mut value) = mutex.lock() {
+        *value = mode;
+    }
 }
 
-fn is_normalize_whitespace_enabled() -> bool {
+/// Set the prefix every utility carries, like Tailwind v3's `prefix: "tw-"` (empty for none)
+pub fn set_class_prefix(prefix: &str) {
+    let mutex = CLASS_PREFIX.get_or_init(|| Mutex::new(String::new()));
+    if let Ok(mut value) = mutex.lock() {
+        *value = prefix.to_string();
+    }
+}
+
+/// Set the string between variants and the utility, like Tailwind v3's `separator: "_"`
+pub fn set_variant_separator(separator: &str) {
+    let mutex = VARIANT_SEPARATOR.get_or_init(|| Mutex::new(":".to_string()));
+    if let Ok(mut value) = mutex.lock() {
+        *value = if separator.is_empty() {
+            ":".to_string()
+        } else {
+            separator.to_string()
+        };
+    }
+}
+
+pub fn is_normalize_whitespace_enabled() -> bool {
     let mutex = NORMALIZE_WHITESPACE.get_or_init(|| Mutex::new(true));
     mutex.lock().map(|guard| *guard).unwrap_or(true)
 }
 
+/// Get the configured print width, if wrapping is enabled
+pub fn get_print_width() -> Option<usize> {
+    let mutex = PRINT_WIDTH.get_or_init(|| Mutex::new(0));
+    let width = mutex.lock().map(|guard| *guard).unwrap_or(0);
+    (width > 0).then_some(width)
+}
+
+pub fn get_output_mode() -> OutputMode {
+    let mutex = OUTPUT_MODE.get_or_init(|| Mutex::new(OutputMode::Flat));
+    mutex.lock().map(|guard| *guard).unwrap_or(OutputMode::Flat)
+}
+
 fn is_remove_duplicates_enabled() -> bool {
     let mutex = REMOVE_DUPLICATES.get_or_init(|| Mutex::new(true));
     mutex.lock().map(|guard| *guard).unwrap_or(true)
@@ -59,33 +157,7 @@ pub fn is_arbitrary_class(class: &str) -> bool {
 
 /// Check if a class is a variant (has a colon but is not an arbitrary property with a colon)
 pub fn is_variant(class: &str) -> bool {
-    // First, check if it's an arbitrary property - in that case, the colon might be within brackets
-    if is_arbitrary_class(class) {
-        // For arbitrary properties, we need to check if the colon is outside brackets/parentheses
-        let mut inside_brackets = false;
-        let mut inside_parens = false;
-        let mut has_variant_colon = false;
-
-        for c in class.chars() {
-            match c {
-                '[' => inside_brackets = true,
-                ']' => inside_brackets = false,
-                '(' => inside_parens = true,
-                ')' => inside_parens = false,
-                ':' => {
-                    if !inside_brackets && !inside_parens {
-                        has_variant_colon = true;
-                    }
-                }
-                _ => {}
-            }
-        }
-
-        return has_variant_colon;
-    }
-
-    // For normal classes, just check if it contains a colon
-    class.contains(':')
+    !split_variants(class).0.is_empty()
 }
 
 /// Extract the property/attribute part from an arbitrary class or custom property
@@ -101,17 +173,17 @@ pub fn extract_arbitrary_attribute(class: &str) -> &str {
     }
 
     // Prefixed arbitrary value like "bg-[#ff0000]"
-    if let Some(bracket_start) = class.find('[') {
-        if let Some(bracket_end) = class.rfind(']') {
-            return &class[bracket_start + 1..bracket_end];
-        }
+    if let Some(bracket_start) = class.find('[')
+        && let Some(bracket_end) = class.rfind(']')
+    {
+        return &class[bracket_start + 1..bracket_end];
     }
 
     // Prefixed CSS custom property with Tailwind v4 syntax like "bg-(--color)"
-    if let Some(paren_start) = class.find('(') {
-        if let Some(paren_end) = class.rfind(')') {
-            return &class[paren_start + 1..paren_end];
-        }
+    if let Some(paren_start) = class.find('(')
+        && let Some(paren_end) = class.rfind(')')
+    {
+        return &class[paren_start + 1..paren_end];
     }
 
     // Default fallback
@@ -131,15 +203,13 @@ pub fn split_preserving_brackets(class_string: &str) -> Vec<&str> {
             ']' => bracket_depth -= 1,
             '(' => paren_depth += 1,
             ')' => paren_depth -= 1,
-            ' ' => {
-                // Only split on spaces outside of brackets/parentheses
-                if bracket_depth == 0 && paren_depth == 0 {
-                    let substring = class_string[start..i].trim();
-                    if !substring.is_empty() {
-                        result.push(substring);
-                    }
-                    start = i + 1; // Skip the space
+            // Only split on spaces outside of brackets/parentheses
+            ' ' if bracket_depth == 0 && paren_depth == 0 => {
+                let substring = class_string[start..i].trim();
+                if !substring.is_empty() {
+                    result.push(substring);
                 }
+                start = i + 1; // Skip the space
             }
             _ => {}
         }
@@ -200,17 +270,9 @@ fn sort_with_preserved_whitespace(
         // Update bracket and parenthesis depth
         match c {
             '[' => bracket_depth += 1,
-            ']' => {
-                if bracket_depth > 0 {
-                    bracket_depth -= 1
-                }
-            }
+            ']' if bracket_depth > 0 => bracket_depth -= 1,
             '(' => paren_depth += 1,
-            ')' => {
-                if paren_depth > 0 {
-                    paren_depth -= 1
-                }
-            }
+            ')' if paren_depth > 0 => paren_depth -= 1,
             _ => {}
         }
 
@@ -660,17 +722,13 @@ pub fn sort_classes(class_string: &str) -> String {
 
     // Don't sort class attributes containing `{{`, to match Prettier behavior
     if class_string.contains("{{") {
-        if is_debug_enabled() {
-            debug_log!("Not sorting classes containing '{{': {}", class_string);
-        }
+        debug_log!("Not sorting classes containing '{{': {}", class_string);
         return class_string.to_string();
     }
 
     // Check for template expressions
     if contains_template_expr(class_string) {
-        if is_debug_enabled() {
-            debug_log!("Sorting template literal expression: {}", class_string);
-        }
+        debug_log!("Sorting template literal expression: {}", class_string);
 
         // Use special handling for template literals
         return sort_template_literal(class_string);
@@ -680,8 +738,8 @@ pub fn sort_classes(class_string: &str) -> String {
     let classes = split_preserving_brackets(class_string);
 
     // Handle ellipsis special case
-    let has_ellipsis = classes.iter().any(|&c| c == "...");
-    let has_unicode_ellipsis = classes.iter().any(|&c| c == "…");
+    let has_ellipsis = classes.contains(&"...");
+    let has_unicode_ellipsis = classes.contains(&"…");
     let classes_without_ellipsis: Vec<&str> = classes
         .iter()
         .filter(|&&c| c != "..." && c != "…")
@@ -706,10 +764,8 @@ pub fn sort_classes(class_string: &str) -> String {
         result.push("…");
     }
 
-    if is_debug_enabled() {
-        debug_log!("Original: {}", class_string);
-        debug_log!("Sorted:   {}", result.join(" "));
-    }
+    trace_log!("Original: {}", class_string);
+    trace_log!("Sorted:   {}", result.join(" "));
 
     // Normalize whitespace if enabled
     if is_normalize_whitespace_enabled() {
@@ -720,43 +776,159 @@ pub fn sort_classes(class_string: &str) -> String {
     }
 }
 
+/// Sort a plain class string and return its non-empty category groups in output order.
+///
+/// Returns `None` for strings the sorter leaves alone or handles specially
+/// (template expressions, `{{` interpolation, whitespace-only input).
+pub fn sort_classes_grouped(class_string: &str) -> Option<Vec<(ClassCategory, Vec<&str>)>> {
+    if class_string.trim().is_empty()
+        || class_string.contains("{{")
+        || contains_template_expr(class_string)
+    {
+        return None;
+    }
+
+    let classes = split_preserving_brackets(class_string);
+
+    // Handle ellipsis special case
+    let ellipsis = if classes.contains(&"...") {
+        Some("...")
+    } else if classes.contains(&"…") {
+        Some("…")
+    } else {
+        None
+    };
+    let classes_without_ellipsis: Vec<&str> = classes
+        .iter()
+        .filter(|&&c| c != "..." && c != "…")
+        .copied()
+        .collect();
+
+    let mut groups: Vec<(ClassCategory, Vec<&str>)> =
+        group_tailwind_classes(&classes_without_ellipsis)
+            .into_iter()
+            .map(|(category, group)| {
+                // Duplicates always land in the same category, so deduplicating per group is enough
+                if is_remove_duplicates_enabled() {
+                    (category, remove_duplicates_from_sorted(&group).0)
+                } else {
+                    (category, group)
+                }
+            })
+            .filter(|(_, group)| !group.is_empty())
+            .collect();
+
+    // Keep the ellipsis at the very end, attached to the last group
+    if let Some(ellipsis) = ellipsis {
+        match groups.last_mut() {
+            Some((_, group)) => group.push(ellipsis),
+            None => groups.push((ClassCategory::Custom, vec![ellipsis])),
+        }
+    }
+
+    Some(groups)
+}
+
 /// Sort Tailwind classes by their CSS property order
 fn sort_tailwind_classes<'a>(classes: &[&'a str]) -> Vec<&'a str> {
+    group_tailwind_classes(classes)
+        .into_iter()
+        .flat_map(|(_, group)| group)
+        .collect()
+}
+
+/// Decide which category bucket a class is sorted in
+pub(crate) fn class_category(class: &str) -> ClassCategory {
+    // Handle fully arbitrary properties (but not arbitrary variants like `[&>*]:p-[10px]`)
+    if ((class.starts_with('[') && class.ends_with(']'))
+        || (class.starts_with('(') && class.ends_with(')')))
+        && !is_variant(class)
+    {
+        let content = extract_arbitrary_attribute(class);
+        return match content.find(':') {
+            // Arbitrary with colon - any order less than 1000 is a known property
+            Some(colon_idx) if find_order(&content[0..colon_idx]) < 1000 => {
+                tailwind_category(class)
+            }
+            // Unknown property with colon, placed at the end
+            Some(_) => ClassCategory::UnknownArbitrary,
+            // Arbitrary without colon - placed with custom classes
+            None => ClassCategory::Arbitrary,
+        };
+    }
+
+    if is_tailwind_class(class) {
+        tailwind_category(class)
+    } else {
+        ClassCategory::Custom
+    }
+}
+
+/// Check for the `group` and `peer` marker classes, including named ones like `group/sidebar`
+fn is_group_marker(class: &str) -> bool {
+    match class
+        .strip_prefix("group")
+        .or_else(|| class.strip_prefix("peer"))
+    {
+        Some("") => true,
+        Some(name) => name
+            .strip_prefix('/')
+            .is_some_and(|name| !name.is_empty() && !name.contains(['/', ':', '['])),
+        None => false,
+    }
+}
+
+/// Bucket for a class already known to be a Tailwind class
+fn tailwind_category(class: &str) -> ClassCategory {
+    let mutex = CLASS_PREFIX.get_or_init(|| Mutex::new(String::new()));
+    match mutex.lock() {
+        Ok(prefix) => tailwind_category_with_prefix(class, &prefix),
+        Err(_) => tailwind_category_with_prefix(class, ""),
+    }
+}
+
+fn tailwind_category_with_prefix(class: &str, prefix: &str) -> ClassCategory {
+    // `tw-container` and `tw-group` are the container and marker classes under a `tw-` prefix
+    let utility = remove_class_prefix(class, prefix).unwrap_or(class);
+
+    if utility == "container" {
+        ClassCategory::Container
+    } else if is_group_marker(utility) {
+        ClassCategory::Parasite
+    } else if !is_variant(class) {
+        ClassCategory::Base
+    } else if highest_variant(&split_variants(class).0).is_some_and(sorts_after_breakpoints) {
+        // Breakpoints and everything Tailwind orders after them, such as `dark:` and `print:`
+        ClassCategory::ResponsiveVariant
+    } else {
+        ClassCategory::StateVariant
+    }
+}
+
+/// Partition classes into their categories and sort each one
+pub(crate) fn group_tailwind_classes<'a>(
+    classes: &[&'a str],
+) -> Vec<(ClassCategory, Vec<&'a str>)> {
     // Separate classes by type
     let mut custom_classes = Vec::new();
     let mut arbitrary_without_colon = Vec::new();
     let mut arbitrary_unknown_with_colon = Vec::new();
-    let mut tailwind_classes = Vec::new();
+    let mut container_class = Vec::new();
+    let mut parasite_utilities = Vec::new();
+    let mut base_utilities = Vec::new();
+    let mut state_variants = Vec::new();
+    let mut responsive_variants = Vec::new();
 
     for &class in classes {
-        // Handle fully arbitrary properties
-        if (class.starts_with('[') && class.ends_with(']'))
-            || (class.starts_with('(') && class.ends_with(')'))
-        {
-            let content = extract_arbitrary_attribute(class);
-            if content.contains(':') {
-                // Arbitrary with colon - check if it matches a known property
-                let colon_idx = content.find(':').unwrap();
-                let prop_name = &content[0..colon_idx];
-
-                // Check if it's a recognized CSS property directly
-                if find_order(prop_name) < 1000 {
-                    // Any order less than 1000 is a known property
-                    tailwind_classes.push(class);
-                } else {
-                    // Unknown property with colon, add to separate list for end placement
-                    arbitrary_unknown_with_colon.push(class);
-                }
-            } else {
-                // Arbitrary without colon - add with custom classes
-                arbitrary_without_colon.push(class);
-            }
-        }
-        // Handle all other classes
-        else if is_tailwind_class(class) {
-            tailwind_classes.push(class);
-        } else {
-            custom_classes.push(class);
+        match class_category(class) {
+            ClassCategory::Custom => custom_classes.push(class),
+            ClassCategory::Arbitrary => arbitrary_without_colon.push(class),
+            ClassCategory::Container => container_class.push(class),
+            ClassCategory::Parasite => parasite_utilities.push(class),
+            ClassCategory::Base => base_utilities.push(class),
+            ClassCategory::StateVariant => state_variants.push(class),
+            ClassCategory::ResponsiveVariant => responsive_variants.push(class),
+            ClassCategory::UnknownArbitrary => arbitrary_unknown_with_colon.push(class),
         }
     }
 
@@ -766,46 +938,28 @@ fn sort_tailwind_classes<'a>(classes: &[&'a str]) -> Vec<&'a str> {
     // Sort unknown arbitrary with colon alphabetically
     arbitrary_unknown_with_colon.sort();
 
-    // Process the remaining Tailwind classes
-    let (container_class, remaining_tailwind): (Vec<&str>, Vec<&str>) = tailwind_classes
-        .iter()
-        .copied()
-        .partition(|&c| c == "container");
-
-    // Group utilities by parasite, base, state variants, responsive variants
-    let (parasite_utilities, non_parasite): (Vec<&str>, Vec<&str>) = remaining_tailwind
-        .iter()
-        .copied()
-        .partition(|&c| c == "group" || c == "peer");
-
-    // Split variants (those with :) from base utilities
-    let (variants, base_utilities): (Vec<&str>, Vec<&str>) =
-        non_parasite.iter().copied().partition(|&c| is_variant(c));
-
-    // Split responsive variants from state variants
-    let (responsive_variants, state_variants): (Vec<&str>, Vec<&str>) =
-        variants.iter().copied().partition(|&c| {
-            let (variant, _) = split_variant(c);
-            ["sm:", "md:", "lg:", "xl:", "2xl:"].contains(&variant)
-        });
-
     // Sort the various categories
     let sorted_base = sort_basic_utilities(&base_utilities);
-    let sorted_state = sort_state_variants(&state_variants);
-    let sorted_responsive = sort_responsive_variants(&responsive_variants);
+    let sorted_state = sort_variants(&state_variants);
+    let sorted_responsive = sort_variants(&responsive_variants);
 
     // Combine all sorted categories in the correct order
-    let mut result = Vec::new();
-    result.extend(custom_classes); // Custom classes
-    result.extend(arbitrary_without_colon); // Arbitrary without colons (alphabetical)
-    result.extend(container_class); // Container
-    result.extend(parasite_utilities); // Parasite utilities
-    result.extend(sorted_base); // Base utilities (including matching arbitrary properties)
-    result.extend(sorted_state); // State variants
-    result.extend(sorted_responsive); // Responsive variants
-    result.extend(arbitrary_unknown_with_colon); // Unknown arbitrary with colons
-
-    result
+    vec![
+        (ClassCategory::Custom, custom_classes),
+        // Arbitrary without colons (alphabetical)
+        (ClassCategory::Arbitrary, arbitrary_without_colon),
+        (ClassCategory::Container, container_class),
+        (ClassCategory::Parasite, parasite_utilities),
+        // Base utilities (including matching arbitrary properties)
+        (ClassCategory::Base, sorted_base),
+        (ClassCategory::StateVariant, sorted_state),
+        (ClassCategory::ResponsiveVariant, sorted_responsive),
+        // Unknown arbitrary with colons
+        (
+            ClassCategory::UnknownArbitrary,
+            arbitrary_unknown_with_colon,
+        ),
+    ]
 }
 
 /// Sort basic utilities (including arbitrary values)
@@ -818,9 +972,11 @@ fn sort_basic_utilities<'a>(base_utilities: &[&'a str]) -> Vec<&'a str> {
         utilities_with_order.push((class, order, sub_prop, is_negative, value));
     }
 
-    // Sort by property order, then sub-property, then negative status, then value
+    // Sort by property order, then sub-property, then negative status, then value, and finally
+    // by the class itself so that every input order gives the same output
     utilities_with_order.sort_by(
-        |&(_, order_a, ref sub_a, neg_a, ref val_a), &(_, order_b, ref sub_b, neg_b, ref val_b)| {
+        |&(class_a, order_a, ref sub_a, neg_a, ref val_a),
+         &(class_b, order_b, ref sub_b, neg_b, ref val_b)| {
             // Property order
             match order_a.cmp(&order_b) {
                 std::cmp::Ordering::Equal => {
@@ -830,8 +986,8 @@ fn sort_basic_utilities<'a>(base_utilities: &[&'a str]) -> Vec<&'a str> {
                             // Negative status (negative first)
                             match neg_b.cmp(&neg_a) {
                                 std::cmp::Ordering::Equal => {
-                                    // Value
-                                    val_a.cmp(val_b)
+                                    // Value, then the class
+                                    val_a.cmp(val_b).then_with(|| class_a.cmp(class_b))
                                 }
                                 other => other,
                             }
@@ -851,139 +1007,182 @@ fn sort_basic_utilities<'a>(base_utilities: &[&'a str]) -> Vec<&'a str> {
         .collect()
 }
 
-/// Sort state variants (hover:, focus:, etc.)
-fn sort_state_variants<'a>(variants: &[&'a str]) -> Vec<&'a str> {
-    // Group variants by their base prefix (hover:, focus:, etc.)
-    let mut grouped: HashMap<&str, Vec<&'a str>> = HashMap::new();
+/// Sort variant classes by the combined order of their variants, then by base class
+fn sort_variants<'a>(variants: &[&'a str]) -> Vec<&'a str> {
+    let mut sorted = variants.to_vec();
 
-    for &class in variants {
-        let (variant, _) = split_variant(class);
-        grouped.entry(variant).or_default().push(class);
-    }
+    sorted.sort_by(|&a, &b| {
+        let (a_variants, a_base) = split_variants(a);
+        let (b_variants, b_base) = split_variants(b);
 
-    let mut variant_groups: Vec<(&str, usize)> = grouped
-        .keys()
-        .map(|&prefix| (prefix, get_state_variant_order(prefix)))
-        .collect();
+        let (a_order, a_sub, a_neg, a_val) = get_property_order(a_base);
+        let (b_order, b_sub, b_neg, b_val) = get_property_order(b_base);
 
-    // Sort by state variant order (hover before focus, etc.)
-    variant_groups.sort_by_key(|&(_, order)| order);
+        // Sort by variants, then property order, sub-property, negative status (negative
+        // first) and value, and finally by the class itself like Tailwind does
+        compare_variant_chains(&a_variants, &b_variants)
+            .then_with(|| a_order.cmp(&b_order))
+            .then_with(|| a_sub.cmp(&b_sub))
+            .then_with(|| b_neg.cmp(&a_neg))
+            .then_with(|| a_val.cmp(&b_val))
+            .then_with(|| a.cmp(b))
+    });
 
-    let mut result = Vec::new();
+    sorted
+}
 
-    for (prefix, _) in variant_groups {
-        if let Some(classes) = grouped.get(prefix) {
-            // Sort classes within this variant group by their base class order
-            let mut variant_classes = classes.to_vec();
-
-            variant_classes.sort_by(|&a, &b| {
-                let (_, a_base) = split_variant(a);
-                let (_, b_base) = split_variant(b);
-
-                let (a_order, a_sub, a_neg, a_val) = get_property_order(a_base);
-                let (b_order, b_sub, b_neg, b_val) = get_property_order(b_base);
-
-                // Sort by property order, then sub-property, then negative status, then value
-                match a_order.cmp(&b_order) {
-                    std::cmp::Ordering::Equal => {
-                        match a_sub.cmp(&b_sub) {
-                            std::cmp::Ordering::Equal => {
-                                match b_neg.cmp(&a_neg) {
-                                    // Negative comes first
-                                    std::cmp::Ordering::Equal => a_val.cmp(&b_val),
-                                    other => other,
-                                }
-                            }
-                            other => other,
-                        }
-                    }
-                    other => other,
-                }
-            });
+/// Strip the important modifier from a utility, written as a prefix (`!p-4`, Tailwind v3)
+/// or a suffix (`p-4!`, Tailwind v4)
+pub fn strip_important(utility: &str) -> Option<&str> {
+    utility
+        .strip_prefix('!')
+        .or_else(|| utility.strip_suffix('!'))
+}
 
-            result.extend(variant_classes);
-        }
-    }
+/// Strip the configured class prefix from a utility (`tw-p-4` to `p-4`).
+///
+/// Returns `None` when a prefix is configured and the utility doesn't carry it.
+pub fn strip_class_prefix(utility: &str) -> Option<&str> {
+    let mutex = CLASS_PREFIX.get_or_init(|| Mutex::new(String::new()));
+    mutex
+        .lock()
+        .map(|prefix| remove_class_prefix(utility, &prefix))
+        .unwrap_or(Some(utility))
+}
 
-    result
+fn remove_class_prefix<'a>(utility: &'a str, prefix: &str) -> Option<&'a str> {
+    if prefix.is_empty() {
+        Some(utility)
+    } else {
+        utility.strip_prefix(prefix)
+    }
 }
 
-/// Sort responsive variants (sm:, md:, lg:, etc.)
-fn sort_responsive_variants<'a>(variants: &[&'a str]) -> Vec<&'a str> {
-    // Group variants by their base prefix (sm:, md:, etc.)
-    let mut grouped: HashMap<&str, Vec<&'a str>> = HashMap::new();
+/// Split an opacity or line-height modifier from a color or font-size utility, e.g.
+/// `bg-red-500/50` into `bg-red-500` and `50`.
+///
+/// Modifiers can be numbers, fractions, arbitrary values or custom properties, or the container
+/// name of `@container/main`. Utilities that don't take a modifier keep their slash, so
+/// fractions like `w-1/2` stay intact.
+pub fn split_value_modifier(utility: &str) -> Option<(&str, &str)> {
+    let mut depth = 0usize;
+    let slash = utility.char_indices().find_map(|(i, c)| {
+        match c {
+            '[' | '(' => depth += 1,
+            ']' | ')' => depth = depth.saturating_sub(1),
+            '/' if depth == 0 => return Some(i),
+            _ => {}
+        }
+        None
+    })?;
 
-    for &class in variants {
-        let (variant, _) = split_variant(class);
-        grouped.entry(variant).or_default().push(class);
+    let (value, modifier) = (&utility[..slash], &utility[slash + 1..]);
+    if (is_modifier_value(modifier) && accepts_modifier(value))
+        || is_container_name(value, modifier)
+    {
+        Some((value, modifier))
+    } else {
+        None
     }
+}
 
-    let mut variant_groups: Vec<(&str, usize)> = grouped
-        .keys()
-        .map(|&prefix| (prefix, get_responsive_variant_order(prefix)))
-        .collect();
+fn is_modifier_value(modifier: &str) -> bool {
+    let is_number = |part: &str| !part.is_empty() && part.parse::<f64>().is_ok();
 
-    // Sort by responsive variant order (sm before md, etc.)
-    variant_groups.sort_by_key(|&(_, order)| order);
+    is_number(modifier)
+        || modifier
+            .split_once('/')
+            .is_some_and(|(numerator, denominator)| is_number(numerator) && is_number(denominator))
+        || (modifier.len() > 2 && modifier.starts_with('[') && modifier.ends_with(']'))
+        || (modifier.starts_with("(--") && modifier.ends_with(')'))
+}
 
-    let mut result = Vec::new();
+/// Check for the container name of an `@container` utility, e.g. `main` in `@container/main`
+fn is_container_name(utility: &str, name: &str) -> bool {
+    utility.starts_with("@container")
+        && !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
 
-    for (prefix, _) in variant_groups {
-        if let Some(classes) = grouped.get(prefix) {
-            // Sort classes within this variant group by their base class order
-            let mut variant_classes = classes.to_vec();
-
-            variant_classes.sort_by(|&a, &b| {
-                let (_, a_base) = split_variant(a);
-                let (_, b_base) = split_variant(b);
-
-                let (a_order, a_sub, a_neg, a_val) = get_property_order(a_base);
-                let (b_order, b_sub, b_neg, b_val) = get_property_order(b_base);
-
-                // Sort by property order, then sub-property, then negative status, then value
-                match a_order.cmp(&b_order) {
-                    std::cmp::Ordering::Equal => {
-                        match a_sub.cmp(&b_sub) {
-                            std::cmp::Ordering::Equal => {
-                                match b_neg.cmp(&a_neg) {
-                                    // Negative comes first
-                                    std::cmp::Ordering::Equal => a_val.cmp(&b_val),
-                                    other => other,
-                                }
-                            }
-                            other => other,
-                        }
-                    }
-                    other => other,
-                }
-            });
+/// Check whether a utility is a color or font-size utility, the ones that take modifiers
+fn accepts_modifier(utility: &str) -> bool {
+    let font_size = find_order("font-size");
+
+    get_tailwind_prefixes().iter().any(|prefix| {
+        !prefix.is_standalone
+            && (prefix.order == font_size
+                || prefix.value_types.contains(&ValueType::Color)
+                || prefix.value_types.contains(&ValueType::ColorPalette))
+            && utility
+                .strip_prefix(&*prefix.prefix)
+                .is_some_and(|value| is_valid_value_for_prefix(prefix, value))
+    })
+}
 
-            result.extend(variant_classes);
+/// Split a class into its chain of variants (without colons) and its base utility.
+///
+/// Colons inside brackets and parentheses, as in `[&:hover]:underline`, don't split.
+pub fn split_variants(class: &str) -> (Vec<&str>, &str) {
+    let mutex = VARIANT_SEPARATOR.get_or_init(|| Mutex::new(":".to_string()));
+    match mutex.lock() {
+        Ok(separator) => split_variants_with(class, &separator),
+        Err(_) => split_variants_with(class, ":"),
+    }
+}
+
+fn split_variants_with<'a>(class: &'a str, separator: &str) -> (Vec<&'a str>, &'a str) {
+    let mut variants = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (i, c) in class.char_indices() {
+        match c {
+            '[' | '(' => depth += 1,
+            ']' | ')' => depth = depth.saturating_sub(1),
+            _ if depth == 0 && i >= start && class[i..].starts_with(separator) => {
+                variants.push(&class[start..i]);
+                start = i + separator.len();
+            }
+            _ => {}
         }
     }
 
-    result
+    (variants, &class[start..])
 }
 
-/// Split a class into variant and base parts
-fn split_variant(class: &str) -> (&str, &str) {
-    if let Some(pos) = class.find(':') {
-        (&class[0..=pos], &class[pos + 1..])
-    } else {
-        ("", class)
-    }
+/// Split a class into its variant prefix (including the trailing colon) and base parts
+pub fn split_variant(class: &str) -> (&str, &str) {
+    let (_, base) = split_variants(class);
+    class.split_at(class.len() - base.len())
 }
 
 /// Get CSS property order information for a class
 fn get_property_order(class: &str) -> (usize, String, bool, String) {
+    resolve_property_order(class).1
+}
+
+/// Get CSS property order information for a class, along with the prefix it matched
+pub(crate) fn resolve_property_order(
+    class: &str,
+) -> (Option<TailwindPrefix>, (usize, String, bool, String)) {
     // Handle special case for ellipsis
     if class == "..." || class == "…" {
-        return (usize::MAX, String::new(), false, String::new());
+        return (None, (usize::MAX, String::new(), false, String::new()));
     }
 
+    // Important classes sort like the utility they modify
+    let class = strip_important(class).unwrap_or(class);
+
     // Handle negative values
     let is_negative = class.starts_with('-') && !class.starts_with("--");
     let lookup_class = if is_negative { &class[1..] } else { class };
+    let lookup_class = strip_class_prefix(lookup_class).unwrap_or(lookup_class);
+
+    // Opacity and line-height modifiers don't change the sort order
+    let lookup_class =
+        split_value_modifier(lookup_class).map_or(lookup_class, |(utility, _)| utility);
 
     // Handle arbitrary values: both prefixed arbitrary values (bg-[red]) and full arbitrary properties ([color:red])
     if is_arbitrary_class(lookup_class) {
@@ -997,10 +1196,13 @@ fn get_property_order(class: &str) -> (usize, String, bool, String) {
 
             let order = find_order(prop_name);
             return (
-                order,
-                String::new(),
-                is_negative,
-                content[colon_idx + 1..].to_string(),
+                None,
+                (
+                    order,
+                    String::new(),
+                    is_negative,
+                    content[colon_idx + 1..].to_string(),
+                ),
             );
         }
 
@@ -1012,9 +1214,10 @@ fn get_property_order(class: &str) -> (usize, String, bool, String) {
 
                 if !prefix.is_empty() {
                     // This is a prefixed arbitrary value
-                    let matching_prefixes: Vec<&TailwindPrefix> = get_tailwind_prefixes()
+                    let prefixes = get_tailwind_prefixes();
+                    let matching_prefixes: Vec<&TailwindPrefix> = prefixes
                         .iter()
-                        .filter(|p| !p.is_standalone && prefix.starts_with(p.prefix))
+                        .filter(|p| !p.is_standalone && prefix.starts_with(&*p.prefix))
                         .collect();
 
                     // Try to find an exact prefix match
@@ -1022,10 +1225,13 @@ fn get_property_order(class: &str) -> (usize, String, bool, String) {
                         matching_prefixes.iter().find(|&&p| p.prefix == prefix)
                     {
                         return (
-                            prefix_info.order,
-                            String::new(),
-                            is_negative,
-                            class.to_string(),
+                            Some((*prefix_info).clone()),
+                            (
+                                prefix_info.order,
+                                String::new(),
+                                is_negative,
+                                class.to_string(),
+                            ),
                         );
                     }
 
@@ -1035,10 +1241,13 @@ fn get_property_order(class: &str) -> (usize, String, bool, String) {
 
                     if let Some(prefix_info) = sorted_prefixes.first() {
                         return (
-                            prefix_info.order,
-                            String::new(),
-                            is_negative,
-                            class.to_string(),
+                            Some((*prefix_info).clone()),
+                            (
+                                prefix_info.order,
+                                String::new(),
+                                is_negative,
+                                class.to_string(),
+                            ),
                         );
                     }
                 }
@@ -1046,7 +1255,10 @@ fn get_property_order(class: &str) -> (usize, String, bool, String) {
         }
 
         // Default for unknown arbitrary properties
-        return (1000, String::new(), is_negative, lookup_class.to_string());
+        return (
+            None,
+            (1000, String::new(), is_negative, lookup_class.to_string()),
+        );
     }
 
     // Find matching prefix for non-arbitrary classes
@@ -1057,7 +1269,7 @@ fn get_property_order(class: &str) -> (usize, String, bool, String) {
 
         if !prefix.is_standalone && prefix.prefix.ends_with('-') {
             let base_class = if lookup_class.contains(':') {
-                lookup_class.split(':').last().unwrap_or(lookup_class)
+                lookup_class.split(':').next_back().unwrap_or(lookup_class)
             } else {
                 lookup_class
             };
@@ -1075,27 +1287,28 @@ fn get_property_order(class: &str) -> (usize, String, bool, String) {
             }
         }
 
-        return (prefix.order, sub_property, is_negative, value);
+        let order = prefix.order;
+        return (Some(prefix), (order, sub_property, is_negative, value));
     }
 
     // Default for unknown classes
-    (1000, String::new(), is_negative, class.to_string())
+    (None, (1000, String::new(), is_negative, class.to_string()))
 }
 
 /// Find matching Tailwind prefix for a class
-fn find_matching_prefix(class: &str) -> Option<&'static TailwindPrefix> {
+fn find_matching_prefix(class: &str) -> Option<TailwindPrefix> {
     // Extract base class without variants
     let base_class = if class.contains(':') {
-        class.split(':').last().unwrap_or(class)
+        class.split(':').next_back().unwrap_or(class)
     } else {
         class
     };
 
+    let prefixes = get_tailwind_prefixes();
+
     // Handle container special case
     if base_class == "container" {
-        return get_tailwind_prefixes()
-            .iter()
-            .find(|&p| p.prefix == "container");
+        return prefixes.iter().find(|&p| p.prefix == "container").cloned();
     }
 
     // Handle arbitrary values
@@ -1104,17 +1317,17 @@ fn find_matching_prefix(class: &str) -> Option<&'static TailwindPrefix> {
     }
 
     // Check for standalone utilities (exact matches)
-    if let Some(prefix) = get_tailwind_prefixes()
+    if let Some(prefix) = prefixes
         .iter()
         .find(|&p| p.is_standalone && p.prefix == base_class)
     {
-        return Some(prefix);
+        return Some(prefix.clone());
     }
 
     // Check for prefixed utilities
-    let mut matches: Vec<&'static TailwindPrefix> = get_tailwind_prefixes()
+    let mut matches: Vec<&TailwindPrefix> = prefixes
         .iter()
-        .filter(|&p| !p.is_standalone && base_class.starts_with(p.prefix))
+        .filter(|&p| !p.is_standalone && base_class.starts_with(&*p.prefix))
         .collect();
 
     // Sort by prefix length (descending) to find most specific match
@@ -1132,50 +1345,13 @@ fn find_matching_prefix(class: &str) -> Option<&'static TailwindPrefix> {
         // Check all prefixes of the same length and find one that accepts the value
         for &prefix in matches.iter() {
             if prefix.prefix.len() == longest_len && is_valid_value_for_prefix(prefix, value_part) {
-                return Some(prefix);
+                return Some(prefix.clone());
             }
         }
     }
 
     // Fall back to the longest match if no valid prefix was found
-    matches.into_iter().next()
-}
-
-/// Get state variant order for sorting
-fn get_state_variant_order(variant: &str) -> usize {
-    match variant {
-        "hover:" => 100,
-        "focus:" => 200,
-        "focus-visible:" => 210,
-        "focus-within:" => 220,
-        "active:" => 300,
-        "visited:" => 400,
-        "checked:" => 500,
-        "disabled:" => 600,
-        "group-hover:" => 700,
-        "group-focus:" => 710,
-        "peer-hover:" => 800,
-        "peer-focus:" => 810,
-        "dark:" => 900,
-        "first:" => 1000,
-        "last:" => 1100,
-        "only:" => 1200,
-        "odd:" => 1300,
-        "even:" => 1400,
-        _ => 1500,
-    }
-}
-
-/// Get responsive variant order for sorting
-fn get_responsive_variant_order(variant: &str) -> usize {
-    match variant {
-        "sm:" => 100,
-        "md:" => 200,
-        "lg:" => 300,
-        "xl:" => 400,
-        "2xl:" => 500,
-        _ => 600,
-    }
+    matches.into_iter().next().cloned()
 }
 
 /// Check if a value is valid for a given prefix
@@ -1185,8 +1361,14 @@ fn is_valid_value_for_prefix(prefix: &TailwindPrefix, value: &str) -> bool {
         return value.is_empty();
     }
 
-    // Check the list of allowed values first
-    if prefix.allowed_values.contains(&value) {
+    // Font sizes from the project's theme, which may replace Tailwind's own
+    let is_font_size = prefix.order == find_order("font-size");
+    if is_font_size && is_theme_font_size(value) {
+        return true;
+    }
+
+    // Then the list of allowed values
+    if prefix.allowed_values.contains(&value) && !(is_font_size && replaces_default_font_sizes()) {
         return true;
     }
 
@@ -1198,8 +1380,11 @@ fn is_valid_value_for_prefix(prefix: &TailwindPrefix, value: &str) -> bool {
                 return value.is_empty();
             }
             ValueType::Number => {
-                // Check if value is a number (integer or decimal)
-                if value.parse::<f64>().is_ok() {
+                // Check if value is a number (integer or decimal) or a theme spacing name,
+                // unless the theme removed the spacing scale the number would come from
+                let is_scale_number = value.parse::<f64>().is_ok()
+                    && !(replaces_default_spacing() && uses_spacing_scale(prefix.order));
+                if is_scale_number || is_theme_spacing(value) {
                     return true;
                 }
             }
@@ -1216,28 +1401,15 @@ fn is_valid_value_for_prefix(prefix: &TailwindPrefix, value: &str) -> bool {
                 }
             }
             ValueType::Color => {
-                // Check for basic colors
-                if ["white", "black", "transparent", "current", "inherit"].contains(&value) {
+                // Check for keywords every color utility accepts (inherit, current, ...)
+                if COLOR_KEYWORDS.contains(&value) {
                     return true;
                 }
             }
             ValueType::ColorPalette => {
-                // Check for color-number pattern (e.g., red-500)
-                let parts: Vec<&str> = value.split('-').collect();
-                if parts.len() == 2 {
-                    let color = parts[0];
-                    let number = parts[1];
-
-                    if [
-                        "slate", "gray", "zinc", "neutral", "stone", "red", "orange", "amber",
-                        "yellow", "lime", "green", "emerald", "teal", "cyan", "sky", "blue",
-                        "indigo", "violet", "purple", "fuchsia", "pink", "rose",
-                    ]
-                    .contains(&color)
-                        && number.parse::<usize>().is_ok()
-                    {
-                        return true;
-                    }
+                // Check for palette colors with a known shade (e.g., red-500) and theme colors
+                if is_color(value) {
+                    return true;
                 }
             }
             ValueType::CustomProperty => {
@@ -1265,6 +1437,11 @@ fn is_valid_value_for_prefix(prefix: &TailwindPrefix, value: &str) -> bool {
                     return true;
                 }
             }
+            ValueType::Any => {
+                if !value.is_empty() {
+                    return true;
+                }
+            }
             ValueType::Scale => {
                 // Check common scale values
                 if [
@@ -1285,8 +1462,7 @@ fn is_valid_value_for_prefix(prefix: &TailwindPrefix, value: &str) -> bool {
 /// Determine if a class is a valid Tailwind utility
 pub fn is_tailwind_class(class: &str) -> bool {
     // Special cases
-    if class == "..." || class == "…" || class == "container" || class == "group" || class == "peer"
-    {
+    if class == "..." || class == "…" {
         return true;
     }
 
@@ -1301,11 +1477,31 @@ pub fn is_tailwind_class(class: &str) -> bool {
         return is_tailwind_class(base);
     }
 
+    // Handle the important modifier
+    if let Some(utility) = strip_important(class) {
+        return is_tailwind_class(utility);
+    }
+
     // Handle negative classes
     if class.starts_with('-') && !class.starts_with("--") {
         return is_tailwind_class(&class[1..]);
     }
 
+    // Utilities carry the configured class prefix, if any (tw-p-4)
+    strip_class_prefix(class).is_some_and(is_tailwind_utility)
+}
+
+/// Determine if a utility without variants, important or negative markers is valid
+fn is_tailwind_utility(class: &str) -> bool {
+    if class == "container" || is_group_marker(class) {
+        return true;
+    }
+
+    // Handle opacity and line-height modifiers (bg-red-500/50, text-sm/6)
+    if let Some((utility, _)) = split_value_modifier(class) {
+        return is_tailwind_utility(utility);
+    }
+
     // Check for arbitrary values with prefixes (like bg-[red]) - MOVED DOWN!
     if is_arbitrary_class(class) {
         // If it's an arbitrary class like [color:red], check for a valid property
@@ -1327,7 +1523,7 @@ pub fn is_tailwind_class(class: &str) -> bool {
                 // Check if this prefix matches any Tailwind prefix
                 return get_tailwind_prefixes()
                     .iter()
-                    .any(|p| !p.is_standalone && prefix.starts_with(p.prefix));
+                    .any(|p| !p.is_standalone && prefix.starts_with(&*p.prefix));
             }
         }
 
@@ -1342,7 +1538,7 @@ pub fn is_tailwind_class(class: &str) -> bool {
         } else {
             // For prefixed utilities like "px-4", extract the value part and check if it's valid
             let value_part = &class[prefix.prefix.len()..];
-            return is_valid_value_for_prefix(prefix, value_part);
+            return is_valid_value_for_prefix(&prefix, value_part);
         }
     }
 
@@ -1588,11 +1784,58 @@ mod tests {
     test! {
         fn test_group_peer_variants() {
             let input = "group-hover:bg-blue-400 peer-hover:bg-green-400 hover:bg-red-400";
-            let expected = "hover:bg-red-400 group-hover:bg-blue-400 peer-hover:bg-green-400";
+            // Tailwind v4 registers group-* and peer-* before the pseudo-class variants
+            let expected = "group-hover:bg-blue-400 peer-hover:bg-green-400 hover:bg-red-400";
             assert_eq!(sort_classes(input), expected);
         }
     }
 
+    test! {
+        fn test_v4_variant_order() {
+            // Expected output from prettier-plugin-tailwindcss with Tailwind v4
+            let corpus = [
+                (
+                    "dark:bg-black md:flex hover:underline p-4",
+                    "p-4 hover:underline md:flex dark:bg-black",
+                ),
+                (
+                    "print:hidden rtl:text-right motion-safe:transition portrait:block",
+                    "motion-safe:transition portrait:block rtl:text-right print:hidden",
+                ),
+                (
+                    "data-[state=open]:block aria-checked:bg-blue-500 has-checked:ring-2 in-focus:opacity-100",
+                    "in-focus:opacity-100 has-checked:ring-2 aria-checked:bg-blue-500 data-[state=open]:block",
+                ),
+                (
+                    "lg:flex max-md:hidden min-[900px]:grid sm:block",
+                    "max-md:hidden sm:block min-[900px]:grid lg:flex",
+                ),
+                (
+                    "focus-within:ring-2 first:pt-0 not-last:mb-2 *:p-2",
+                    "*:p-2 not-last:mb-2 first:pt-0 focus-within:ring-2",
+                ),
+                (
+                    "forced-colors:border starting:opacity-0 dark:text-white",
+                    "dark:text-white starting:opacity-0 forced-colors:border",
+                ),
+            ];
+
+            for (input, expected) in corpus {
+                assert_eq!(sort_classes(input), expected, "sorting {:?}", input);
+            }
+        }
+    }
+
+    test! {
+        fn test_named_group_and_peer() {
+            let input = "group-hover/item:text-white p-4 group/item peer/label group-hover:underline peer-checked/label:flex";
+            let expected = "group/item peer/label p-4 group-hover:underline group-hover/item:text-white peer-checked/label:flex";
+            assert_eq!(sort_classes(input), expected);
+            assert!(!is_tailwind_class("group/"));
+            assert!(!is_tailwind_class("groups/item"));
+        }
+    }
+
     test! {
         fn test_nested_variants() {
             let input = "sm:hover:bg-blue-500 hover:sm:bg-blue-500";
@@ -1601,6 +1844,144 @@ mod tests {
         }
     }
 
+    test! {
+        fn test_stacked_variants() {
+            let input = "dark:md:hover:bg-blue-500 md:flex hover:underline md:dark:p-4 md:hover:p-2 dark:bg-black";
+            let expected = "hover:underline md:flex md:hover:p-2 dark:bg-black md:dark:p-4 dark:md:hover:bg-blue-500";
+            assert_eq!(sort_classes(input), expected);
+        }
+    }
+
+    test! {
+        fn test_stacked_variants_with_brackets() {
+            assert_eq!(split_variants("md:[&:hover]:underline"), (vec!["md", "[&:hover]"], "underline"));
+            assert_eq!(split_variant("md:[&:hover]:underline"), ("md:[&:hover]:", "underline"));
+            assert_eq!(split_variant("[color:red]"), ("", "[color:red]"));
+            assert!(is_tailwind_class("md:[&:hover]:underline"));
+        }
+    }
+
+    test! {
+        fn test_arbitrary_variants() {
+            let input = "[@media(min-width:900px)]:grid [&>*]:p-4 hover:flex [&:nth-child(3)]:underline group-[.is-open]:block p-2 data-[state=open]:flex";
+            let expected = "p-2 group-[.is-open]:block hover:flex data-[state=open]:flex [&:nth-child(3)]:underline [&>*]:p-4 [@media(min-width:900px)]:grid";
+            assert_eq!(sort_classes(input), expected);
+        }
+    }
+
+    test! {
+        fn test_arbitrary_variant_with_arbitrary_value() {
+            let input = "[&>*]:p-[10px] mt-2";
+            let expected = "mt-2 [&>*]:p-[10px]";
+            assert_eq!(sort_classes(input), expected);
+            assert_eq!(class_category("[&>*]:p-[10px]"), ClassCategory::ResponsiveVariant);
+        }
+    }
+
+    test! {
+        fn test_important_modifier() {
+            let input = "!mt-0 p-4! md:hidden! flex md:!p-2 -mb-2!";
+            let expected = "!mt-0 -mb-2! flex p-4! md:hidden! md:!p-2";
+            assert_eq!(sort_classes(input), expected);
+        }
+    }
+
+    test! {
+        fn test_important_is_not_a_duplicate() {
+            let input = "p-4 p-4! flex p-4";
+            let expected = "flex p-4 p-4!";
+            assert_eq!(sort_classes(input), expected);
+        }
+    }
+
+    test! {
+        fn test_equal_utilities_sort_canonically() {
+            for input in ["p-4! p-4", "p-4 p-4!"] {
+                assert_eq!(sort_classes(input), "p-4 p-4!");
+            }
+            for input in ["bg-red-500/50 bg-red-500", "bg-red-500 bg-red-500/50"] {
+                assert_eq!(sort_classes(input), "bg-red-500 bg-red-500/50");
+            }
+        }
+    }
+
+    test! {
+        fn test_class_prefix_and_separator() {
+            // The configured prefix and separator are global, so check the helpers directly
+            assert_eq!(remove_class_prefix("tw-p-4", "tw-"), Some("p-4"));
+            assert_eq!(remove_class_prefix("p-4", "tw-"), None);
+            assert_eq!(remove_class_prefix("p-4", ""), Some("p-4"));
+
+            assert_eq!(
+                tailwind_category_with_prefix("tw-container", "tw-"),
+                ClassCategory::Container
+            );
+            for marker in ["tw-group", "tw-peer", "tw-group/sidebar", "tw-peer/input"] {
+                assert_eq!(
+                    tailwind_category_with_prefix(marker, "tw-"),
+                    ClassCategory::Parasite
+                );
+            }
+            assert_eq!(
+                tailwind_category_with_prefix("tw-flex", "tw-"),
+                ClassCategory::Base
+            );
+
+            assert_eq!(
+                split_variants_with("md_hover_tw-bg-[a_b]", "_"),
+                (vec!["md", "hover"], "tw-bg-[a_b]")
+            );
+            assert_eq!(
+                split_variants_with("md__hover__flex", "__"),
+                (vec!["md", "hover"], "flex")
+            );
+        }
+    }
+
+    test! {
+        fn test_container_queries() {
+            for class in ["@container", "@container/main", "@container-size", "@lg/main:flex"] {
+                assert!(is_tailwind_class(class), "{} should be a Tailwind class", class);
+            }
+            assert!(!is_tailwind_class("@container-wide"));
+
+            let input = "@lg/main:flex @sm:p-2 md:flex dark:p-1 @max-md:hidden @container lg:p-1 @min-[400px]:grid @lg:flex p-4";
+            let expected = "@container p-4 md:flex lg:p-1 @max-md:hidden @sm:p-2 @min-[400px]:grid @lg:flex @lg/main:flex dark:p-1";
+            assert_eq!(sort_classes(input), expected);
+        }
+    }
+
+    test! {
+        fn test_opacity_and_line_height_modifiers() {
+            let input = "text-lg/7 bg-black/[.3] w-1/2 text-white/75 custom p-4 bg-(--brand)/(--alpha)";
+            let expected = "custom w-1/2 bg-(--brand)/(--alpha) bg-black/[.3] p-4 text-lg/7 text-white/75";
+            assert_eq!(sort_classes(input), expected);
+
+            assert_eq!(split_value_modifier("bg-red-500/50"), Some(("bg-red-500", "50")));
+            assert_eq!(split_value_modifier("text-sm/[1.25rem]"), Some(("text-sm", "[1.25rem]")));
+            assert_eq!(split_value_modifier("w-1/2"), None);
+            assert_eq!(split_value_modifier("bg-red-500/x"), None);
+        }
+    }
+
+    test! {
+        fn test_v4_colors() {
+            for class in [
+                "bg-taupe-950",
+                "text-mist-500",
+                "border-x-olive-200",
+                "ring-offset-red-500",
+                "placeholder-current",
+                "from-transparent",
+                "decoration-inherit",
+                "fill-(--icon-color)",
+            ] {
+                assert!(is_tailwind_class(class), "{} should be a Tailwind class", class);
+            }
+            assert!(!is_tailwind_class("bg-red-501"));
+        }
+    }
+
     test! {
         fn test_empty_string() {
             let input = "";
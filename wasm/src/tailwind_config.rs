@@ -0,0 +1,633 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use oxc::allocator::Allocator;
+use oxc::ast::ast::{
+    ArrayExpressionElement, AssignmentTarget, Declaration, Expression, ImportDeclarationSpecifier,
+    ObjectPropertyKind, Program, Statement, VariableDeclaration,
+};
+use oxc::parser::Parser;
+use oxc::span::{GetSpan, SourceType};
+
+use crate::theme::{Theme, apply_theme, read_plugin};
+use crate::variants::length_in_px;
+use crate::{debug_log, warn_log};
+
+/// How deep references between top-level constants are followed before giving up
+const MAX_DEPTH: usize = 32;
+
+/// Parse a `tailwind.config.{js,ts}` file and use its theme for all sorting from now on
+pub fn load_tailwind_config(source: &str, file_extension: &str) -> Theme {
+    let theme = parse_tailwind_config(source, file_extension);
+    apply_theme(&theme);
+    theme
+}
+
+/// Read the theme, `prefix`, `separator` and `plugins` of a Tailwind v3 config file without
+/// running it. Keys that don't change the class order, like `important`, are ignored.
+///
+/// Only values written out as literals, or as constants and spreads of literals in the same
+/// file, can be read. Everything else is reported in the theme's warnings.
+pub fn parse_tailwind_config(source: &str, file_extension: &str) -> Theme {
+    let allocator = Allocator::default();
+    let fake_path = format!("tailwind.config.{}", file_extension);
+    let source_type = SourceType::from_path(Path::new(&fake_path)).unwrap_or_default();
+    let ret = Parser::new(&allocator, source, source_type).parse();
+
+    let mut theme = Theme::default();
+
+    if let Some(error) = ret.errors.first() {
+        theme
+            .warnings
+            .push(format!("Can't parse the Tailwind config: {}", error));
+    } else {
+        let mut evaluator = Evaluator::new(source, &ret.program);
+        match evaluator.exported_config(&ret.program) {
+            Some(Value::Object(config)) => read_config(&config, &mut theme),
+            Some(value) => theme.warnings.push(format!(
+                "Can't resolve the exported Tailwind config ({})",
+                value.describe()
+            )),
+            None => theme.warnings.push(
+                "No `module.exports` or `export default` found in the Tailwind config".to_string(),
+            ),
+        }
+        theme.warnings.splice(0..0, evaluator.warnings);
+    }
+
+    for warning in &theme.warnings {
+        warn_log!("{}", warning);
+    }
+    debug_log!(
+        "Read Tailwind config with {} colors and {} breakpoints",
+        theme.colors.len(),
+        theme.breakpoints.len()
+    );
+
+    theme
+}
+
+/// A statically evaluated JavaScript value
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    String(String),
+    Number(f64),
+    Bool(bool),
+    /// Object entries in source order, later duplicates replacing earlier ones
+    Object(Vec<(String, Value)>),
+    Array(Vec<Value>),
+    Function,
+    /// A module loaded with `require` or `import`, or something called from one
+    Module(String),
+    /// An expression that can't be evaluated without running the config, with its source
+    Unknown(String),
+}
+
+impl Value {
+    fn describe(&self) -> String {
+        match self {
+            Value::String(value) => format!("\"{}\"", value),
+            Value::Number(value) => value.to_string(),
+            Value::Bool(value) => value.to_string(),
+            Value::Object(_) => "an object".to_string(),
+            Value::Array(_) => "an array".to_string(),
+            Value::Function => "a function".to_string(),
+            Value::Module(module) => format!("from \"{}\"", module),
+            Value::Unknown(source) => source.clone(),
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
+
+/// What a top-level name refers to
+enum Binding<'a> {
+    Expression(&'a Expression<'a>),
+    Module(&'a str),
+}
+
+struct Evaluator<'a> {
+    source: &'a str,
+    bindings: HashMap<&'a str, Binding<'a>>,
+    warnings: Vec<String>,
+}
+
+impl<'a> Evaluator<'a> {
+    /// Collect the top-level constants and imports of a program
+    fn new(source: &'a str, program: &'a Program<'a>) -> Self {
+        let mut bindings = HashMap::new();
+
+        for statement in &program.body {
+            let declaration = match statement {
+                Statement::VariableDeclaration(declaration) => Some(&**declaration),
+                Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                    Some(Declaration::VariableDeclaration(declaration)) => Some(&**declaration),
+                    _ => None,
+                },
+                Statement::ImportDeclaration(import) => {
+                    for specifier in import.specifiers.iter().flatten() {
+                        let local = match specifier {
+                            ImportDeclarationSpecifier::ImportSpecifier(s) => &s.local,
+                            ImportDeclarationSpecifier::ImportDefaultSpecifier(s) => &s.local,
+                            ImportDeclarationSpecifier::ImportNamespaceSpecifier(s) => &s.local,
+                        };
+                        bindings.insert(
+                            local.name.as_str(),
+                            Binding::Module(import.source.value.as_str()),
+                        );
+                    }
+                    None
+                }
+                _ => None,
+            };
+            if let Some(declaration) = declaration {
+                collect_declaration(declaration, &mut bindings);
+            }
+        }
+
+        Evaluator {
+            source,
+            bindings,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Evaluate the object assigned to `module.exports` or exported by default, unwrapping
+    /// helpers such as `defineConfig({ ... })` and `withMT({ ... })`
+    fn exported_config(&mut self, program: &'a Program<'a>) -> Option<Value> {
+        let mut expression = program.body.iter().rev().find_map(|statement| match statement {
+            Statement::ExpressionStatement(statement) => match &statement.expression {
+                Expression::AssignmentExpression(assignment) => match &assignment.left {
+                    AssignmentTarget::StaticMemberExpression(member)
+                        if member.property.name == "exports"
+                            && matches!(&member.object, Expression::Identifier(id) if id.name == "module") =>
+                    {
+                        Some(&assignment.right)
+                    }
+                    _ => None,
+                },
+                _ => None,
+            },
+            Statement::ExportDefaultDeclaration(export) => export.declaration.as_expression(),
+            _ => None,
+        })?;
+
+        for _ in 0..MAX_DEPTH {
+            expression = match expression.get_inner_expression() {
+                Expression::Identifier(id) => match self.bindings.get(id.name.as_str()) {
+                    Some(Binding::Expression(init)) => init,
+                    _ => break,
+                },
+                Expression::CallExpression(call) if !call.is_require_call() => {
+                    match call.arguments.first().and_then(|arg| arg.as_expression()) {
+                        Some(argument) => argument,
+                        None => break,
+                    }
+                }
+                _ => break,
+            };
+        }
+
+        Some(self.evaluate(expression, "config", 0))
+    }
+
+    fn evaluate(&mut self, expression: &'a Expression<'a>, path: &str, depth: usize) -> Value {
+        let source = self.source;
+        let unknown = || Value::Unknown(expression.span().source_text(source).to_string());
+        if depth > MAX_DEPTH {
+            return unknown();
+        }
+
+        match expression.get_inner_expression() {
+            Expression::StringLiteral(literal) => Value::String(literal.value.to_string()),
+            Expression::NumericLiteral(literal) => Value::Number(literal.value),
+            Expression::BooleanLiteral(literal) => Value::Bool(literal.value),
+            Expression::TemplateLiteral(literal) if literal.expressions.is_empty() => literal
+                .quasi()
+                .map_or_else(unknown, |quasi| Value::String(quasi.to_string())),
+            Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_) => {
+                Value::Function
+            }
+            Expression::ObjectExpression(object) => {
+                let mut entries = Vec::new();
+                for property in &object.properties {
+                    match property {
+                        ObjectPropertyKind::ObjectProperty(property) => {
+                            let Some(key) = property.key.static_name() else {
+                                self.warnings.push(format!(
+                                    "Can't resolve the computed key {} in {}",
+                                    property.key.span().source_text(self.source),
+                                    path
+                                ));
+                                continue;
+                            };
+                            let value = if property.method {
+                                Value::Function
+                            } else {
+                                let path = format!("{}.{}", path, key);
+                                self.evaluate(&property.value, &path, depth + 1)
+                            };
+                            insert(&mut entries, key.to_string(), value);
+                        }
+                        ObjectPropertyKind::SpreadProperty(spread) => {
+                            match self.evaluate(&spread.argument, path, depth + 1) {
+                                Value::Object(spread) => {
+                                    for (key, value) in spread {
+                                        insert(&mut entries, key, value);
+                                    }
+                                }
+                                value => self.warnings.push(format!(
+                                    "Can't resolve the spread of {} in {}",
+                                    value.describe(),
+                                    path
+                                )),
+                            }
+                        }
+                    }
+                }
+                Value::Object(entries)
+            }
+            Expression::ArrayExpression(array) => {
+                let mut elements = Vec::new();
+                for element in &array.elements {
+                    match element {
+                        ArrayExpressionElement::SpreadElement(spread) => {
+                            match self.evaluate(&spread.argument, path, depth + 1) {
+                                Value::Array(spread) => elements.extend(spread),
+                                value => self.warnings.push(format!(
+                                    "Can't resolve the spread of {} in {}",
+                                    value.describe(),
+                                    path
+                                )),
+                            }
+                        }
+                        ArrayExpressionElement::Elision(_) => {}
+                        _ => {
+                            if let Some(expression) = element.as_expression() {
+                                elements.push(self.evaluate(expression, path, depth + 1));
+                            }
+                        }
+                    }
+                }
+                Value::Array(elements)
+            }
+            Expression::Identifier(id) => match self.bindings.get(id.name.as_str()) {
+                Some(Binding::Expression(init)) => self.evaluate(init, path, depth + 1),
+                Some(Binding::Module(module)) => Value::Module(module.to_string()),
+                None => unknown(),
+            },
+            Expression::StaticMemberExpression(member) => {
+                match self.evaluate(&member.object, path, depth + 1) {
+                    object @ Value::Object(_) => object
+                        .get(member.property.name.as_str())
+                        .cloned()
+                        .unwrap_or_else(unknown),
+                    _ => unknown(),
+                }
+            }
+            Expression::CallExpression(call) => {
+                if call.is_require_call() {
+                    match call.arguments.first() {
+                        Some(argument) => match argument.as_expression() {
+                            Some(Expression::StringLiteral(module)) => {
+                                Value::Module(module.value.to_string())
+                            }
+                            _ => unknown(),
+                        },
+                        None => unknown(),
+                    }
+                } else {
+                    // Calling what a module exports, like `require("plugin")({ ... })`
+                    match self.evaluate(&call.callee, path, depth + 1) {
+                        module @ Value::Module(_) => module,
+                        _ => unknown(),
+                    }
+                }
+            }
+            _ => unknown(),
+        }
+    }
+}
+
+fn collect_declaration<'a>(
+    declaration: &'a VariableDeclaration<'a>,
+    bindings: &mut HashMap<&'a str, Binding<'a>>,
+) {
+    for declarator in &declaration.declarations {
+        if let (Some(name), Some(init)) = (declarator.id.get_binding_identifier(), &declarator.init)
+        {
+            bindings.insert(name.name.as_str(), Binding::Expression(init));
+        }
+    }
+}
+
+/// Set an object entry, replacing an earlier entry with the same key like JavaScript does
+fn insert(entries: &mut Vec<(String, Value)>, key: String, value: Value) {
+    match entries.iter_mut().find(|(k, _)| *k == key) {
+        Some(entry) => entry.1 = value,
+        None => entries.push((key, value)),
+    }
+}
+
+fn read_config(config: &[(String, Value)], theme: &mut Theme) {
+    for (key, value) in config {
+        match key.as_str() {
+            "prefix" => match value {
+                Value::String(prefix) => theme.prefix = Some(prefix.clone()),
+                _ => theme.warnings.push(format!(
+                    "Can't resolve config.prefix ({})",
+                    value.describe()
+                )),
+            },
+            "separator" => match value {
+                Value::String(separator) => theme.separator = Some(separator.clone()),
+                _ => theme.warnings.push(format!(
+                    "Can't resolve config.separator ({})",
+                    value.describe()
+                )),
+            },
+            "theme" => match value {
+                Value::Object(_) => {
+                    read_theme_section(value, "config.theme", false, theme);
+                    if let Some(extend) = value.get("extend") {
+                        read_theme_section(extend, "config.theme.extend", true, theme);
+                    }
+                }
+                _ => theme
+                    .warnings
+                    .push(format!("Can't resolve config.theme ({})", value.describe())),
+            },
+            "plugins" => match value {
+                Value::Array(plugins) => {
+                    for plugin in plugins {
+                        match plugin {
+                            Value::Module(module) => read_plugin(module, theme),
+                            _ => theme.warnings.push(format!(
+                                "Can't identify the plugin {} in config.plugins",
+                                plugin.describe()
+                            )),
+                        }
+                    }
+                }
+                _ => theme.warnings.push(format!(
+                    "Can't resolve config.plugins ({})",
+                    value.describe()
+                )),
+            },
+            _ => {}
+        }
+    }
+}
+
+/// Read the keys the sorter cares about from `theme` or `theme.extend`
+fn read_theme_section(section: &Value, path: &str, extend: bool, theme: &mut Theme) {
+    let Value::Object(entries) = section else {
+        theme
+            .warnings
+            .push(format!("Can't resolve {} ({})", path, section.describe()));
+        return;
+    };
+
+    for (key, value) in entries {
+        let path = format!("{}.{}", path, key);
+        // Keys set outside `extend` replace Tailwind's defaults for that key
+        match key.as_str() {
+            "colors" => {
+                theme.replace_colors |= !extend;
+                read_colors("", value, &path, theme);
+            }
+            "spacing" => {
+                theme.replace_spacing |= !extend;
+                read_names(value, &path, &mut theme.spacing, &mut theme.warnings);
+            }
+            "fontSize" => {
+                theme.replace_font_sizes |= !extend;
+                read_names(value, &path, &mut theme.font_sizes, &mut theme.warnings);
+            }
+            "screens" => {
+                theme.replace_breakpoints |= !extend;
+                read_screens(value, &path, theme);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Flatten nested color objects into class names, e.g. `brand: { DEFAULT, 500 }` into
+/// `brand` and `brand-500`
+fn read_colors(name: &str, value: &Value, path: &str, theme: &mut Theme) {
+    match value {
+        Value::Object(shades) => {
+            for (key, value) in shades {
+                let color = match (name, key.as_str()) {
+                    (_, "DEFAULT") => name.to_string(),
+                    ("", key) => key.to_string(),
+                    (name, key) => format!("{}-{}", name, key),
+                };
+                read_colors(&color, value, &format!("{}.{}", path, key), theme);
+            }
+        }
+        _ if name.is_empty() => {
+            theme
+                .warnings
+                .push(format!("Can't resolve {} ({})", path, value.describe()))
+        }
+        Value::Unknown(_) | Value::Module(_) => {
+            // The name is known, but not whether the value is a color or a set of shades
+            push_unique(&mut theme.colors, name);
+            theme.warnings.push(format!(
+                "Can't resolve {} ({}); only `{}` itself is known",
+                path,
+                value.describe(),
+                name
+            ));
+        }
+        _ => push_unique(&mut theme.colors, name),
+    }
+}
+
+fn read_names(value: &Value, path: &str, names: &mut Vec<String>, warnings: &mut Vec<String>) {
+    match value {
+        Value::Object(entries) => {
+            for (name, _) in entries {
+                push_unique(names, name);
+            }
+        }
+        _ => warnings.push(format!("Can't resolve {} ({})", path, value.describe())),
+    }
+}
+
+/// Read screens written as `"640px"` or `{ min: "640px" }`
+fn read_screens(value: &Value, path: &str, theme: &mut Theme) {
+    let Value::Object(screens) = value else {
+        theme
+            .warnings
+            .push(format!("Can't resolve {} ({})", path, value.describe()));
+        return;
+    };
+
+    for (name, screen) in screens {
+        let width = match screen {
+            Value::String(width) => Some(width),
+            Value::Object(_) => match screen.get("min") {
+                Some(Value::String(width)) => Some(width),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        match width.and_then(|width| length_in_px(width)) {
+            Some(px) => match theme.breakpoints.iter_mut().find(|(n, _)| n == name) {
+                Some(breakpoint) => breakpoint.1 = px,
+                None => theme.breakpoints.push((name.clone(), px)),
+            },
+            None => theme.warnings.push(format!(
+                "Can't order screen {} with width {}",
+                name,
+                screen.describe()
+            )),
+        }
+    }
+}
+
+fn push_unique(names: &mut Vec<String>, name: &str) {
+    if !names.iter().any(|n| n == name) {
+        names.push(name.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r##"
+import type { Config } from "tailwindcss";
+import colors from "tailwindcss/colors";
+
+const brand = {
+  DEFAULT: "#0af",
+  500: "#08c",
+  dark: { 900: "#012" },
+};
+
+export default {
+  prefix: "tw-",
+  important: "#app",
+  separator: "_",
+  content: ["./src/**/*.{ts,tsx}"],
+  theme: {
+    screens: { tablet: "40rem", desktop: { min: "1024px" }, print: { raw: "print" } },
+    extend: {
+      colors: {
+        brand,
+        accent: ({ opacityValue }) => `rgb(0 0 0 / ${opacityValue})`,
+        primary: colors.indigo,
+      },
+      spacing: { 18: "4.5rem", gutter: "1.5rem" },
+      fontSize: { tiny: ["0.625rem", { lineHeight: "1rem" }] },
+      screens: { "3xl": "1920px" },
+    },
+  },
+  plugins: [require("@tailwindcss/typography"), require("./plugin.js")({ strict: true })],
+} satisfies Config;
+"##;
+
+    #[test]
+    fn test_parse_tailwind_config() {
+        let theme = parse_tailwind_config(CONFIG, "ts");
+
+        assert_eq!(theme.prefix.as_deref(), Some("tw-"));
+        assert_eq!(theme.separator.as_deref(), Some("_"));
+        assert_eq!(
+            theme.colors,
+            vec!["brand", "brand-500", "brand-dark-900", "accent", "primary"]
+        );
+        assert_eq!(theme.spacing, vec!["18", "gutter"]);
+        assert_eq!(theme.font_sizes, vec!["tiny"]);
+        assert!(!theme.replace_colors && !theme.replace_spacing && !theme.replace_font_sizes);
+        assert!(theme.replace_breakpoints);
+        assert_eq!(
+            theme.breakpoints,
+            vec![
+                ("tablet".to_string(), 640.0),
+                ("desktop".to_string(), 1024.0),
+                ("3xl".to_string(), 1920.0),
+            ]
+        );
+        assert_eq!(
+            theme.plugins,
+            vec!["@tailwindcss/typography", "./plugin.js"]
+        );
+        assert_eq!(theme.utilities[0].name, "prose");
+
+        // The raw print screen, the imported color and the unknown plugin
+        assert_eq!(theme.warnings.len(), 3, "{:?}", theme.warnings);
+        assert!(theme.warnings.iter().any(|w| w.contains("colors.indigo")));
+    }
+
+    #[test]
+    fn test_theme_outside_extend_replaces_defaults() {
+        let source = r##"
+module.exports = {
+  theme: {
+    colors: { brand: "#0af" },
+    spacing: { gutter: "1.5rem" },
+    fontSize: { tiny: "0.625rem" },
+    extend: { colors: { accent: "#f0a" } },
+  },
+};
+"##;
+        let theme = parse_tailwind_config(source, "js");
+
+        assert_eq!(theme.colors, vec!["brand", "accent"]);
+        assert!(theme.replace_colors && theme.replace_spacing && theme.replace_font_sizes);
+        assert!(!theme.replace_breakpoints);
+        assert!(theme.warnings.is_empty(), "{:?}", theme.warnings);
+    }
+
+    #[test]
+    fn test_parse_commonjs_config() {
+        let source = r#"
+const defaultTheme = require("tailwindcss/defaultTheme");
+const screens = { xs: "480px" };
+
+module.exports = {
+  theme: {
+    extend: {
+      screens: { ...screens, ...defaultTheme.screens },
+    },
+  },
+};
+"#;
+        let theme = parse_tailwind_config(source, "js");
+
+        assert_eq!(theme.breakpoints, vec![("xs".to_string(), 480.0)]);
+        assert!(!theme.replace_breakpoints);
+        assert_eq!(theme.warnings.len(), 1);
+        assert!(theme.warnings[0].contains("defaultTheme.screens"));
+    }
+
+    #[test]
+    fn test_unreadable_configs_warn() {
+        let theme = parse_tailwind_config("module.exports = makeConfig;", "js");
+        assert_eq!(
+            theme,
+            Theme {
+                warnings: theme.warnings.clone(),
+                ..Theme::default()
+            }
+        );
+        assert_eq!(theme.warnings.len(), 1);
+
+        let theme = parse_tailwind_config("module.exports = {", "js");
+        assert!(theme.warnings[0].starts_with("Can't parse"));
+
+        let theme = parse_tailwind_config("const x = 1;", "js");
+        assert!(theme.warnings[0].starts_with("No `module.exports`"));
+    }
+}
@@ -0,0 +1,696 @@
+use std::borrow::Cow;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+use serde::Serialize;
+
+use crate::colors::set_theme_colors;
+use crate::prefixes::{TailwindPrefix, ValueType, find_order, set_custom_prefixes};
+use crate::sorter::{set_class_prefix, set_variant_separator};
+use crate::variants::{
+    length_in_px, set_custom_variants, set_theme_breakpoints, set_theme_container_sizes,
+};
+use crate::{debug_log, warn_log};
+
+/// Names from one namespace of the project's theme
+#[derive(Debug, Default)]
+struct ThemeNames {
+    names: Vec<String>,
+    replace_defaults: bool,
+}
+
+/// Spacing names from the project's theme, e.g. `gutter` for `p-gutter`
+static THEME_SPACING: OnceLock<Mutex<ThemeNames>> = OnceLock::new();
+/// Font size names from the project's theme, e.g. `tiny` for `text-tiny`
+static THEME_FONT_SIZES: OnceLock<Mutex<ThemeNames>> = OnceLock::new();
+
+/// Properties whose bare numeric values come from the `--spacing` scale, e.g. `p-4`
+const SPACING_PROPERTIES: &[&str] = &[
+    "inset",
+    "inset-inline",
+    "inset-block",
+    "inset-inline-start",
+    "inset-inline-end",
+    "top",
+    "right",
+    "bottom",
+    "left",
+    "margin",
+    "margin-inline",
+    "margin-block",
+    "margin-inline-start",
+    "margin-inline-end",
+    "margin-top",
+    "margin-right",
+    "margin-bottom",
+    "margin-left",
+    "width",
+    "min-width",
+    "max-width",
+    "height",
+    "min-height",
+    "max-height",
+    "flex-basis",
+    "translate",
+    "border-spacing",
+    "scroll-margin",
+    "scroll-padding",
+    "gap",
+    "column-gap",
+    "row-gap",
+    "padding",
+    "padding-inline",
+    "padding-block",
+    "padding-inline-start",
+    "padding-inline-end",
+    "padding-top",
+    "padding-right",
+    "padding-bottom",
+    "padding-left",
+    "text-indent",
+];
+
+/// Everything the sorter needs to know about a project's Tailwind setup
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Theme {
+    /// Color names, e.g. `brand-600` for `--color-brand-600`
+    pub colors: Vec<String>,
+    /// Whether `colors` replace Tailwind's default palette, after `--color-*: initial`
+    pub replace_colors: bool,
+    pub spacing: Vec<String>,
+    /// Whether `spacing` replaces Tailwind's numeric spacing scale, after `--spacing-*: initial`
+    pub replace_spacing: bool,
+    pub font_sizes: Vec<String>,
+    /// Whether `font_sizes` replace Tailwind's `xs` to `9xl`, after `--text-*: initial`
+    pub replace_font_sizes: bool,
+    /// Breakpoint names with their widths in pixels
+    pub breakpoints: Vec<(String, f64)>,
+    /// Whether `breakpoints` replace Tailwind's default `sm` to `2xl` instead of extending them
+    pub replace_breakpoints: bool,
+    /// Container query sizes with their widths in pixels
+    pub container_sizes: Vec<(String, f64)>,
+    /// Whether `container_sizes` replace Tailwind's default `3xs` to `7xl`
+    pub replace_container_sizes: bool,
+    pub utilities: Vec<CustomUtility>,
+    /// Custom variant names, in declaration order
+    pub variants: Vec<String>,
+    pub plugins: Vec<String>,
+    /// The prefix every utility carries, e.g. `tw-` for `tw-p-4`
+    pub prefix: Option<String>,
+    /// The string between variants and the utility, if it isn't `:`
+    pub separator: Option<String>,
+    /// Declarations the sorter could not make sense of
+    pub warnings: Vec<String>,
+}
+
+impl Theme {
+    /// Combine this theme with another one, e.g. a v3 config with a v4 CSS entry file.
+    ///
+    /// This theme's prefix and separator win, the other theme's names are added to its lists,
+    /// and a reset of the defaults in either theme applies to both.
+    pub fn merge(mut self, other: Theme) -> Theme {
+        extend_unique(&mut self.colors, other.colors);
+        extend_unique(&mut self.spacing, other.spacing);
+        extend_unique(&mut self.font_sizes, other.font_sizes);
+        extend_widths(&mut self.breakpoints, other.breakpoints);
+        extend_widths(&mut self.container_sizes, other.container_sizes);
+        self.utilities.extend(other.utilities);
+        extend_unique(&mut self.variants, other.variants);
+        extend_unique(&mut self.plugins, other.plugins);
+        self.warnings.extend(other.warnings);
+
+        self.replace_colors |= other.replace_colors;
+        self.replace_spacing |= other.replace_spacing;
+        self.replace_font_sizes |= other.replace_font_sizes;
+        self.replace_breakpoints |= other.replace_breakpoints;
+        self.replace_container_sizes |= other.replace_container_sizes;
+
+        self.prefix = self.prefix.or(other.prefix);
+        self.separator = self.separator.or(other.separator);
+
+        self
+    }
+}
+
+fn extend_unique(names: &mut Vec<String>, other: Vec<String>) {
+    for name in other {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+}
+
+/// Add named widths, letting a later width for the same name win
+fn extend_widths(widths: &mut Vec<(String, f64)>, other: Vec<(String, f64)>) {
+    for (name, px) in other {
+        match widths.iter_mut().find(|(n, _)| *n == name) {
+            Some(width) => width.1 = px,
+            None => widths.push((name, px)),
+        }
+    }
+}
+
+/// A utility added with `@utility` or by a plugin
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomUtility {
+    /// The class name, or its prefix including the dash for functional utilities (`tab-`)
+    pub name: String,
+    /// Whether the utility takes a value, like `@utility tab-*`
+    pub functional: bool,
+    /// The first CSS property the utility sets, which decides its sort order
+    pub property: Option<String>,
+}
+
+impl CustomUtility {
+    fn to_prefix(&self) -> TailwindPrefix {
+        TailwindPrefix {
+            order: self.property.as_deref().map_or(1000, find_order),
+            prefix: Cow::Owned(self.name.clone()),
+            value_types: if self.functional {
+                &[ValueType::Any]
+            } else {
+                &[ValueType::None]
+            },
+            allowed_values: &[],
+            is_standalone: !self.functional,
+        }
+    }
+}
+
+pub fn is_theme_spacing(value: &str) -> bool {
+    let mutex = THEME_SPACING.get_or_init(|| Mutex::new(ThemeNames::default()));
+    mutex
+        .lock()
+        .map(|spacing| spacing.names.iter().any(|name| name == value))
+        .unwrap_or(false)
+}
+
+pub fn is_theme_font_size(value: &str) -> bool {
+    let mutex = THEME_FONT_SIZES.get_or_init(|| Mutex::new(ThemeNames::default()));
+    mutex
+        .lock()
+        .map(|font_sizes| font_sizes.names.iter().any(|name| name == value))
+        .unwrap_or(false)
+}
+
+/// Check whether the theme removed Tailwind's numeric spacing scale
+pub fn replaces_default_spacing() -> bool {
+    let mutex = THEME_SPACING.get_or_init(|| Mutex::new(ThemeNames::default()));
+    mutex
+        .lock()
+        .map(|spacing| spacing.replace_defaults)
+        .unwrap_or(false)
+}
+
+/// Check whether the theme removed Tailwind's default font sizes
+pub fn replaces_default_font_sizes() -> bool {
+    let mutex = THEME_FONT_SIZES.get_or_init(|| Mutex::new(ThemeNames::default()));
+    mutex
+        .lock()
+        .map(|font_sizes| font_sizes.replace_defaults)
+        .unwrap_or(false)
+}
+
+/// Check whether a utility's numeric values come from the spacing scale, given its order
+pub fn uses_spacing_scale(order: usize) -> bool {
+    SPACING_PROPERTIES
+        .iter()
+        .any(|&property| find_order(property) == order)
+}
+
+/// Use a theme for all sorting from now on, replacing any theme applied before
+pub fn apply_theme(theme: &Theme) {
+    set_theme_colors(theme.colors.clone(), theme.replace_colors);
+
+    let spacing = THEME_SPACING.get_or_init(|| Mutex::new(ThemeNames::default()));
+    if let Ok(mut value) = spacing.lock() {
+        value.names = theme.spacing.clone();
+        value.replace_defaults = theme.replace_spacing;
+    }
+
+    let font_sizes = THEME_FONT_SIZES.get_or_init(|| Mutex::new(ThemeNames::default()));
+    if let Ok(mut value) = font_sizes.lock() {
+        value.names = theme.font_sizes.clone();
+        value.replace_defaults = theme.replace_font_sizes;
+    }
+
+    set_class_prefix(theme.prefix.as_deref().unwrap_or_default());
+    set_variant_separator(theme.separator.as_deref().unwrap_or(":"));
+    set_theme_breakpoints(theme.breakpoints.clone(), theme.replace_breakpoints);
+    set_theme_container_sizes(theme.container_sizes.clone(), theme.replace_container_sizes);
+    set_custom_variants(theme.variants.clone());
+    set_custom_prefixes(
+        theme
+            .utilities
+            .iter()
+            .map(CustomUtility::to_prefix)
+            .collect(),
+    );
+
+    debug_log!(
+        "Applied theme with {} colors, {} breakpoints, {} utilities and {} variants",
+        theme.colors.len(),
+        theme.breakpoints.len(),
+        theme.utilities.len(),
+        theme.variants.len()
+    );
+}
+
+/// Parse a Tailwind v4 CSS entry file and use its theme for all sorting from now on
+pub fn load_css_theme(css: &str) -> Theme {
+    let theme = parse_css_theme(css);
+    apply_theme(&theme);
+    theme
+}
+
+/// Read the `@theme`, `@utility`, `@custom-variant`, `@variant` and `@plugin` rules of a
+/// Tailwind v4 CSS entry file
+pub fn parse_css_theme(css: &str) -> Theme {
+    let css = strip_comments(css);
+    let mut theme = Theme::default();
+
+    for statement in statements(&css) {
+        let Some(at_rule) = statement.prelude.strip_prefix('@') else {
+            continue;
+        };
+        let (name, params) = at_rule
+            .split_once(|c: char| c.is_whitespace() || c == '(' || c == '"' || c == '\'')
+            .map_or((at_rule, ""), |(name, _)| (name, &at_rule[name.len()..]));
+        let params = params.trim();
+
+        match name {
+            "theme" => {
+                if let Some(body) = statement.body {
+                    read_theme_block(body, &mut theme);
+                }
+            }
+            "utility" => theme.utilities.push(CustomUtility {
+                name: params.trim_end_matches('*').to_string(),
+                functional: params.ends_with("-*"),
+                property: statement.body.and_then(first_property),
+            }),
+            "custom-variant" => push_variant(&mut theme, params),
+            // `@variant` also applies a variant inside a rule; at the top level it only
+            // defines one in its older shorthand or `@slot` forms
+            "variant" if params.contains('(') || statement.body.is_some_and(has_slot) => {
+                push_variant(&mut theme, params)
+            }
+            "plugin" => read_plugin(params.trim_matches(['"', '\'']), &mut theme),
+            _ => {}
+        }
+    }
+
+    for warning in &theme.warnings {
+        warn_log!("{}", warning);
+    }
+
+    theme
+}
+
+fn push_variant(theme: &mut Theme, params: &str) {
+    let name: String = params
+        .chars()
+        .take_while(|&c| !c.is_whitespace() && c != '(' && c != '{')
+        .collect();
+
+    if name.is_empty() {
+        theme
+            .warnings
+            .push(format!("Ignoring variant without a name: {}", params));
+    } else if !theme.variants.contains(&name) {
+        theme.variants.push(name);
+    }
+}
+
+fn has_slot(body: &str) -> bool {
+    body.contains("@slot")
+}
+
+/// Add the utilities of the official plugins the sorter knows about
+pub(crate) fn read_plugin(plugin: &str, theme: &mut Theme) {
+    let component = |name: &str, functional: bool| CustomUtility {
+        name: name.to_string(),
+        functional,
+        property: Some("container-component".to_string()),
+    };
+
+    match plugin {
+        "@tailwindcss/typography" => {
+            theme.utilities.push(component("prose", false));
+            theme.utilities.push(component("prose-", true));
+        }
+        "@tailwindcss/forms" => {
+            for name in [
+                "form-input",
+                "form-textarea",
+                "form-select",
+                "form-multiselect",
+                "form-checkbox",
+                "form-radio",
+            ] {
+                theme.utilities.push(component(name, false));
+            }
+        }
+        _ => theme.warnings.push(format!(
+            "Unknown plugin \"{}\"; its classes are sorted as custom classes",
+            plugin
+        )),
+    }
+
+    theme.plugins.push(plugin.to_string());
+}
+
+/// Read the `--namespace-name: value` declarations of an `@theme` block
+fn read_theme_block(body: &str, theme: &mut Theme) {
+    for statement in statements(body) {
+        if statement.body.is_some() {
+            continue;
+        }
+        let Some((property, value)) = statement.prelude.split_once(':') else {
+            continue;
+        };
+        let (property, value) = (property.trim(), value.trim());
+
+        // Resets like `--color-*: initial` and sub-properties like `--text-xl--line-height`
+        // don't add names
+        let Some(variable) = property.strip_prefix("--") else {
+            continue;
+        };
+        if value == "initial" {
+            if matches!(variable, "*" | "color-*") {
+                theme.replace_colors = true;
+            }
+            if matches!(variable, "*" | "spacing-*") {
+                theme.replace_spacing = true;
+            }
+            if matches!(variable, "*" | "text-*") {
+                theme.replace_font_sizes = true;
+            }
+            if matches!(variable, "*" | "breakpoint-*") {
+                theme.breakpoints.clear();
+                theme.replace_breakpoints = true;
+            }
+            if matches!(variable, "*" | "container-*") {
+                theme.container_sizes.clear();
+                theme.replace_container_sizes = true;
+            }
+        }
+        if variable.contains('*') || variable.contains("--") {
+            continue;
+        }
+
+        if let Some(name) = variable.strip_prefix("color-") {
+            theme.colors.push(name.to_string());
+        } else if let Some(name) = variable.strip_prefix("spacing-") {
+            theme.spacing.push(name.to_string());
+        } else if let Some(name) = variable.strip_prefix("text-") {
+            theme.font_sizes.push(name.to_string());
+        } else if let Some(name) = variable.strip_prefix("breakpoint-") {
+            match length_in_px(value) {
+                Some(px) => theme.breakpoints.push((name.to_string(), px)),
+                None => theme.warnings.push(format!(
+                    "Can't order breakpoint {} with width \"{}\"",
+                    name, value
+                )),
+            }
+        } else if let Some(name) = variable.strip_prefix("container-") {
+            match length_in_px(value) {
+                Some(px) => theme.container_sizes.push((name.to_string(), px)),
+                None => theme.warnings.push(format!(
+                    "Can't order container size {} with width \"{}\"",
+                    name, value
+                )),
+            }
+        }
+    }
+}
+
+/// The first property a rule body sets, skipping custom properties and at-rules
+fn first_property(body: &str) -> Option<String> {
+    statements(body).into_iter().find_map(|statement| {
+        if statement.body.is_some() || statement.prelude.starts_with(['@', '-']) {
+            return None;
+        }
+        let (property, _) = statement.prelude.split_once(':')?;
+        Some(property.trim().to_string())
+    })
+}
+
+/// A top-level statement of a stylesheet or block: a declaration or at-rule ending in `;`,
+/// or a rule or at-rule with a `{}` body
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Statement<'a> {
+    prelude: &'a str,
+    body: Option<&'a str>,
+}
+
+fn statements(css: &str) -> Vec<Statement<'_>> {
+    let mut statements = Vec::new();
+    let mut rest = css;
+
+    while !rest.trim().is_empty() {
+        let (statement, remaining) = read_statement(rest);
+        if !statement.prelude.is_empty() || statement.body.is_some() {
+            statements.push(statement);
+        }
+        rest = remaining;
+    }
+
+    statements
+}
+
+/// Read one statement, skipping over strings, parentheses and nested blocks
+fn read_statement(css: &str) -> (Statement<'_>, &str) {
+    let mut quote = None;
+    let mut parens = 0usize;
+    let mut body_start = None;
+    let mut braces = 0usize;
+
+    for (i, c) in css.char_indices() {
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '(' => parens += 1,
+            ')' => parens = parens.saturating_sub(1),
+            ';' if parens == 0 && body_start.is_none() => {
+                let statement = Statement {
+                    prelude: css[..i].trim(),
+                    body: None,
+                };
+                return (statement, &css[i + 1..]);
+            }
+            '{' if parens == 0 => {
+                if body_start.is_none() {
+                    body_start = Some(i);
+                }
+                braces += 1;
+            }
+            '}' if parens == 0 && braces > 0 => {
+                braces -= 1;
+                if braces == 0
+                    && let Some(start) = body_start
+                {
+                    let statement = Statement {
+                        prelude: css[..start].trim(),
+                        body: Some(&css[start + 1..i]),
+                    };
+                    return (statement, &css[i + 1..]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // An unterminated statement runs to the end of the input
+    let statement = match body_start {
+        Some(start) => Statement {
+            prelude: css[..start].trim(),
+            body: Some(&css[start + 1..]),
+        },
+        None => Statement {
+            prelude: css.trim(),
+            body: None,
+        },
+    };
+    (statement, "")
+}
+
+fn strip_comments(css: &str) -> String {
+    let mut result = String::with_capacity(css.len());
+    let mut rest = css;
+
+    while let Some(start) = rest.find("/*") {
+        result.push_str(&rest[..start]);
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None => "",
+        };
+    }
+    result.push_str(rest);
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENTRY: &str = r#"
+@import "tailwindcss";
+@plugin "@tailwindcss/typography";
+@plugin "./my-plugin.js";
+
+/* Design system tokens */
+@theme {
+  --color-brand-600: oklch(0.55 0.2 260);
+  --color-*: initial;
+  --breakpoint-*: initial;
+  --spacing-gutter: 1.5rem;
+  --text-tiny: 0.625rem;
+  --text-tiny--line-height: 1rem;
+  --breakpoint-3xl: 120rem;
+  --breakpoint-wide: var(--wide);
+  @keyframes spin { to { transform: rotate(360deg); } }
+}
+
+@utility content-auto {
+  content-visibility: auto;
+}
+
+@utility tab-* {
+  --tab: --value(integer);
+  tab-size: var(--tab);
+}
+
+@custom-variant theme-midnight (&:where([data-theme="midnight"] *));
+@custom-variant pointer-hover {
+  @media (hover: hover) { &:hover { @slot; } }
+}
+
+.card { @variant dark { color: white; } }
+"#;
+
+    #[test]
+    fn test_parse_css_theme() {
+        let theme = parse_css_theme(ENTRY);
+
+        assert_eq!(theme.colors, vec!["brand-600"]);
+        assert_eq!(theme.spacing, vec!["gutter"]);
+        assert_eq!(theme.font_sizes, vec!["tiny"]);
+        assert_eq!(theme.breakpoints, vec![("3xl".to_string(), 1920.0)]);
+        assert!(theme.replace_breakpoints && !theme.replace_container_sizes);
+        assert!(theme.replace_colors && !theme.replace_spacing && !theme.replace_font_sizes);
+        assert_eq!(theme.variants, vec!["theme-midnight", "pointer-hover"]);
+        assert_eq!(
+            theme.utilities[2],
+            CustomUtility {
+                name: "content-auto".to_string(),
+                functional: false,
+                property: Some("content-visibility".to_string()),
+            }
+        );
+        assert_eq!(theme.utilities[3].name, "tab-");
+        assert!(theme.utilities[3].functional);
+        assert_eq!(theme.utilities[3].property.as_deref(), Some("tab-size"));
+        assert_eq!(
+            theme.plugins,
+            vec!["@tailwindcss/typography", "./my-plugin.js"]
+        );
+        // The unknown plugin and the breakpoint without a static width
+        assert_eq!(theme.warnings.len(), 2);
+    }
+
+    #[test]
+    fn test_theme_utilities_become_prefixes() {
+        // Applying a theme changes global tables other tests read, so only parse it here
+        let theme = parse_css_theme(
+            r#"
+@theme {
+  --color-ocean-breeze: #0af;
+  --color-ocean-breeze-500: #08c;
+  --breakpoint-3xl: 120rem;
+}
+@utility content-auto { content-visibility: auto; }
+@utility tab-* { tab-size: var(--tab); }
+@custom-variant theme-midnight (&:where([data-theme="midnight"] *));
+"#,
+        );
+
+        assert_eq!(theme.colors, vec!["ocean-breeze", "ocean-breeze-500"]);
+        assert_eq!(theme.breakpoints, vec![("3xl".to_string(), 1920.0)]);
+        assert!(!theme.replace_breakpoints && !theme.replace_colors);
+        assert_eq!(theme.variants, vec!["theme-midnight"]);
+
+        let standalone = theme.utilities[0].to_prefix();
+        assert_eq!(standalone.prefix, "content-auto");
+        assert_eq!(standalone.order, find_order("content-visibility"));
+        assert!(standalone.is_standalone);
+
+        let functional = theme.utilities[1].to_prefix();
+        assert_eq!(functional.prefix, "tab-");
+        assert_eq!(functional.order, find_order("tab-size"));
+        assert!(!functional.is_standalone);
+    }
+
+    #[test]
+    fn test_global_reset_replaces_every_namespace() {
+        let theme = parse_css_theme("@theme { --*: initial; --spacing-gutter: 1.5rem; }");
+
+        assert!(theme.replace_colors && theme.replace_spacing && theme.replace_font_sizes);
+        assert!(theme.replace_breakpoints && theme.replace_container_sizes);
+        assert_eq!(theme.spacing, vec!["gutter"]);
+        assert!(uses_spacing_scale(find_order("padding-left")));
+        assert!(!uses_spacing_scale(find_order("z-index")));
+    }
+
+    #[test]
+    fn test_merge_keeps_prefix_and_extends_lists() {
+        let config = Theme {
+            prefix: Some("tw-".to_string()),
+            colors: vec!["brand".to_string()],
+            breakpoints: vec![("tablet".to_string(), 640.0), ("3xl".to_string(), 1600.0)],
+            ..Theme::default()
+        };
+        let css = parse_css_theme(
+            r#"
+@theme {
+  --color-*: initial;
+  --color-brand: #0af;
+  --color-accent: #f0a;
+  --breakpoint-3xl: 120rem;
+}
+@custom-variant theme-midnight (&:where([data-theme="midnight"] *));
+"#,
+        );
+
+        let theme = config.merge(css);
+
+        assert_eq!(theme.prefix.as_deref(), Some("tw-"));
+        assert_eq!(theme.colors, vec!["brand", "accent"]);
+        assert!(theme.replace_colors && !theme.replace_breakpoints);
+        assert_eq!(
+            theme.breakpoints,
+            vec![("tablet".to_string(), 640.0), ("3xl".to_string(), 1920.0)]
+        );
+        assert_eq!(theme.variants, vec!["theme-midnight"]);
+    }
+
+    #[test]
+    fn test_statements_skip_nested_blocks_and_strings() {
+        let css = r#"a { b { c: d } } @x "semi;colon"; @y (e;f);"#;
+        let statements = statements(css);
+
+        assert_eq!(statements.len(), 3);
+        assert_eq!(statements[0].body, Some(" b { c: d } "));
+        assert_eq!(statements[1].prelude, r#"@x "semi;colon""#);
+        assert_eq!(statements[2].prelude, "@y (e;f)");
+    }
+}
@@ -0,0 +1,843 @@
+use std::cmp::Ordering;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+/// Variant roots in the order Tailwind v4 registers them, which is the order variant classes
+/// are sorted in. Compound (`group-*`) and functional (`aria-*`) variants are listed by root.
+///
+/// `min` also covers the named breakpoints (`sm`, `md`, ...) and `@min` the named container
+/// sizes (`@sm`, `@md`, ...), since Tailwind registers them in the same group.
+const VARIANT_ORDER: &[&str] = &[
+    "*",
+    "**",
+    "not",
+    "group",
+    "peer",
+    "first-letter",
+    "first-line",
+    "marker",
+    "selection",
+    "file",
+    "placeholder",
+    "backdrop",
+    "details-content",
+    "before",
+    "after",
+    "first",
+    "last",
+    "only",
+    "odd",
+    "even",
+    "first-of-type",
+    "last-of-type",
+    "only-of-type",
+    "visited",
+    "target",
+    "open",
+    "default",
+    "checked",
+    "indeterminate",
+    "placeholder-shown",
+    "autofill",
+    "optional",
+    "required",
+    "valid",
+    "invalid",
+    "user-valid",
+    "user-invalid",
+    "in-range",
+    "out-of-range",
+    "read-only",
+    "empty",
+    "focus-within",
+    "hover",
+    "focus",
+    "focus-visible",
+    "active",
+    "enabled",
+    "disabled",
+    "inert",
+    "in",
+    "has",
+    "aria",
+    "data",
+    "nth",
+    "nth-last",
+    "nth-of-type",
+    "nth-last-of-type",
+    "supports",
+    "motion-safe",
+    "motion-reduce",
+    "contrast-more",
+    "contrast-less",
+    "max",
+    "min",
+    "@max",
+    "@min",
+    "portrait",
+    "landscape",
+    "ltr",
+    "rtl",
+    "dark",
+    "starting",
+    "print",
+    "forced-colors",
+    "inverted-colors",
+    "pointer-none",
+    "pointer-coarse",
+    "pointer-fine",
+    "any-pointer-none",
+    "any-pointer-coarse",
+    "any-pointer-fine",
+    "noscript",
+];
+
+/// Variants that wrap another variant, e.g. `group-hover` or `not-first`
+const COMPOUND_VARIANTS: &[&str] = &["not", "group", "peer", "in", "has"];
+
+/// Variants that take a value, longest first so `nth-last-of-type` wins over `nth`
+const FUNCTIONAL_VARIANTS: &[&str] = &[
+    "nth-last-of-type",
+    "nth-of-type",
+    "nth-last",
+    "supports",
+    "aria",
+    "data",
+    "nth",
+];
+
+/// Default breakpoints in pixels (Tailwind defines them in rem at 16px)
+const BREAKPOINTS: &[(&str, f64)] = &[
+    ("sm", 640.0),
+    ("md", 768.0),
+    ("lg", 1024.0),
+    ("xl", 1280.0),
+    ("2xl", 1536.0),
+];
+
+/// Default container query sizes in pixels
+const CONTAINER_SIZES: &[(&str, f64)] = &[
+    ("3xs", 256.0),
+    ("2xs", 288.0),
+    ("xs", 320.0),
+    ("sm", 384.0),
+    ("md", 448.0),
+    ("lg", 512.0),
+    ("xl", 576.0),
+    ("2xl", 672.0),
+    ("3xl", 768.0),
+    ("4xl", 896.0),
+    ("5xl", 1024.0),
+    ("6xl", 1152.0),
+    ("7xl", 1280.0),
+];
+
+/// A variant parsed just enough to compare it the way Tailwind does
+#[derive(Debug, Clone, PartialEq)]
+enum Variant<'a> {
+    Known {
+        order: usize,
+        detail: Detail<'a>,
+    },
+    /// Arbitrary variants like `[&>*]` sort after every known variant, by selector
+    Arbitrary(&'a str),
+    /// Variants Tailwind doesn't know sort last
+    Unknown(&'a str),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Detail<'a> {
+    None,
+    /// The wrapped variant of `group-*`, `peer-*`, `not-*`, `in-*` and `has-*`, and the
+    /// group or peer name from a `/name` modifier
+    Compound(Box<Variant<'a>>, Option<&'a str>),
+    /// A breakpoint or container size; `max` widths sort in descending order. Container
+    /// queries can name the container they apply to, as in `@lg/main`
+    Width {
+        max: bool,
+        px: Option<f64>,
+        name: Option<&'a str>,
+    },
+    /// The value of `aria-*`, `data-*`, `supports-*` and `nth-*`
+    Value(&'a str),
+}
+
+/// Breakpoints or container sizes from the project's theme
+#[derive(Debug, Default)]
+struct ThemeWidths {
+    widths: Vec<(String, f64)>,
+    /// Whether the defaults are dropped, as with `--breakpoint-*: initial` or a v3 `screens` key
+    replace_defaults: bool,
+    /// Widths from the editor or tool settings, which take precedence over the theme's and
+    /// survive loading a new theme
+    configured: Vec<(String, f64)>,
+}
+
+/// Breakpoints added or overridden by the project's theme
+static THEME_BREAKPOINTS: OnceLock<Mutex<ThemeWidths>> = OnceLock::new();
+/// Container sizes added or overridden by the project's theme
+static THEME_CONTAINER_SIZES: OnceLock<Mutex<ThemeWidths>> = OnceLock::new();
+/// Variants registered with `@custom-variant`, in declaration order
+static CUSTOM_VARIANTS: OnceLock<Mutex<Vec<String>>> = OnceLock::new();
+
+/// Set the project's breakpoints as names with min-widths in pixels, either alongside
+/// Tailwind's `sm` to `2xl` or instead of them
+pub fn set_theme_breakpoints(breakpoints: Vec<(String, f64)>, replace_defaults: bool) {
+    let mutex = THEME_BREAKPOINTS.get_or_init(|| Mutex::new(ThemeWidths::default()));
+    if let Ok(mut value) = mutex.lock() {
+        value.widths = breakpoints;
+        value.replace_defaults = replace_defaults;
+    }
+}
+
+/// Set breakpoints from the user's settings, layered over the theme's breakpoints without
+/// changing whether the theme replaces the defaults
+pub fn set_configured_screens(screens: Vec<(String, f64)>) {
+    let mutex = THEME_BREAKPOINTS.get_or_init(|| Mutex::new(ThemeWidths::default()));
+    if let Ok(mut value) = mutex.lock() {
+        value.configured = screens;
+    }
+}
+
+pub fn set_theme_container_sizes(sizes: Vec<(String, f64)>, replace_defaults: bool) {
+    let mutex = THEME_CONTAINER_SIZES.get_or_init(|| Mutex::new(ThemeWidths::default()));
+    if let Ok(mut value) = mutex.lock() {
+        value.widths = sizes;
+        value.replace_defaults = replace_defaults;
+    }
+}
+
+/// Register custom variants, which Tailwind orders after all of its own
+pub fn set_custom_variants(variants: Vec<String>) {
+    let mutex = CUSTOM_VARIANTS.get_or_init(|| Mutex::new(Vec::new()));
+    if let Ok(mut value) = mutex.lock() {
+        *value = variants;
+    }
+}
+
+fn custom_variants_len() -> usize {
+    let mutex = CUSTOM_VARIANTS.get_or_init(|| Mutex::new(Vec::new()));
+    mutex.lock().map(|variants| variants.len()).unwrap_or(0)
+}
+
+fn root_order(root: &str) -> Option<usize> {
+    if let Some(order) = VARIANT_ORDER.iter().position(|&known| known == root) {
+        return Some(order);
+    }
+
+    let mutex = CUSTOM_VARIANTS.get_or_init(|| Mutex::new(Vec::new()));
+    let custom = mutex.lock().ok()?;
+    custom
+        .iter()
+        .position(|variant| variant == root)
+        .map(|index| VARIANT_ORDER.len() + index)
+}
+
+/// Look up a named breakpoint or container size, preferring the settings over the theme and the
+/// theme over the defaults
+fn named_width(name: &str, container: bool) -> Option<f64> {
+    let (theme, defaults) = if container {
+        (&THEME_CONTAINER_SIZES, CONTAINER_SIZES)
+    } else {
+        (&THEME_BREAKPOINTS, BREAKPOINTS)
+    };
+
+    let mutex = theme.get_or_init(|| Mutex::new(ThemeWidths::default()));
+    match mutex.lock() {
+        Ok(theme) => lookup_width(name, &theme, defaults),
+        Err(_) => lookup_width(name, &ThemeWidths::default(), defaults),
+    }
+}
+
+fn lookup_width(name: &str, theme: &ThemeWidths, defaults: &[(&str, f64)]) -> Option<f64> {
+    if let Some((_, px)) = theme
+        .configured
+        .iter()
+        .chain(&theme.widths)
+        .find(|(theme_name, _)| theme_name == name)
+    {
+        return Some(*px);
+    }
+    if theme.replace_defaults {
+        return None;
+    }
+
+    defaults
+        .iter()
+        .find(|&&(default_name, _)| default_name == name)
+        .map(|&(_, px)| px)
+}
+
+/// Convert a CSS length in `px`, `rem` or `em` (at 16px) to pixels
+pub fn length_in_px(length: &str) -> Option<f64> {
+    let length = length.trim();
+    if let Some(px) = length.strip_suffix("px") {
+        px.parse().ok()
+    } else if let Some(rem) = length
+        .strip_suffix("rem")
+        .or_else(|| length.strip_suffix("em"))
+    {
+        rem.parse::<f64>().ok().map(|rem| rem * 16.0)
+    } else {
+        None
+    }
+}
+
+/// Resolve a named size or an arbitrary `[400px]`/`[40rem]` value to pixels
+fn width_in_px(value: &str, container: bool) -> Option<f64> {
+    if let Some(px) = named_width(value, container) {
+        return Some(px);
+    }
+
+    length_in_px(value.strip_prefix('[')?.strip_suffix(']')?)
+}
+
+fn known<'a>(root: &str, detail: Detail<'a>) -> Option<Variant<'a>> {
+    root_order(root).map(|order| Variant::Known { order, detail })
+}
+
+/// Parse a variant without its trailing colon
+fn parse_variant(variant: &str) -> Variant<'_> {
+    if let Some(selector) = variant.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+        return Variant::Arbitrary(selector);
+    }
+
+    // Compound and functional roots need a value, so `group:` alone is not a variant
+    if !COMPOUND_VARIANTS.contains(&variant)
+        && !FUNCTIONAL_VARIANTS.contains(&variant)
+        && let Some(order) = root_order(variant)
+    {
+        return Variant::Known {
+            order,
+            detail: Detail::None,
+        };
+    }
+
+    parse_width_variant(variant)
+        .or_else(|| parse_compound_variant(variant))
+        .or_else(|| parse_functional_variant(variant))
+        .unwrap_or(Variant::Unknown(variant))
+}
+
+/// Breakpoints (`md`, `max-lg`, `min-[400px]`) and container queries (`@md`, `@max-lg`,
+/// `@lg/main`)
+fn parse_width_variant(variant: &str) -> Option<Variant<'_>> {
+    let (container, rest, name) = match variant.strip_prefix('@') {
+        Some(rest) => {
+            let (rest, name) = split_modifier(rest);
+            (true, rest, name)
+        }
+        None => (false, variant, None),
+    };
+    let (max, root, value) = if let Some(value) = rest.strip_prefix("max-") {
+        (true, "max", value)
+    } else if let Some(value) = rest.strip_prefix("min-") {
+        (false, "min", value)
+    } else if named_width(rest, container).is_some() || (container && rest.starts_with('[')) {
+        (false, "min", rest)
+    } else {
+        return None;
+    };
+
+    let root = if container {
+        if max { "@max" } else { "@min" }
+    } else {
+        root
+    };
+    let px = width_in_px(value, container);
+    if px.is_none() && !value.starts_with('[') {
+        return None;
+    }
+
+    known(root, Detail::Width { max, px, name })
+}
+
+fn parse_compound_variant(variant: &str) -> Option<Variant<'_>> {
+    COMPOUND_VARIANTS.iter().find_map(|&root| {
+        let inner = variant.strip_prefix(root)?.strip_prefix('-')?;
+        let (inner, modifier) = split_modifier(inner);
+        known(
+            root,
+            Detail::Compound(Box::new(parse_variant(inner)), modifier),
+        )
+    })
+}
+
+/// Split a trailing `/name` modifier (outside brackets) from a variant
+fn split_modifier(variant: &str) -> (&str, Option<&str>) {
+    let mut depth = 0usize;
+    let mut slash = None;
+
+    for (i, c) in variant.char_indices() {
+        match c {
+            '[' | '(' => depth += 1,
+            ']' | ')' => depth = depth.saturating_sub(1),
+            '/' if depth == 0 => slash = Some(i),
+            _ => {}
+        }
+    }
+
+    match slash {
+        Some(i) if i + 1 < variant.len() => (&variant[..i], Some(&variant[i + 1..])),
+        _ => (variant, None),
+    }
+}
+
+fn parse_functional_variant(variant: &str) -> Option<Variant<'_>> {
+    FUNCTIONAL_VARIANTS.iter().find_map(|&root| {
+        let value = variant.strip_prefix(root)?.strip_prefix('-')?;
+        if value.is_empty() {
+            return None;
+        }
+        known(root, Detail::Value(value))
+    })
+}
+
+fn compare_parsed(a: &Variant, b: &Variant) -> Ordering {
+    match (a, b) {
+        (Variant::Unknown(a), Variant::Unknown(b)) => a.cmp(b),
+        (Variant::Unknown(_), _) => Ordering::Greater,
+        (_, Variant::Unknown(_)) => Ordering::Less,
+        (Variant::Arbitrary(a), Variant::Arbitrary(b)) => compare_selectors(a, b),
+        (Variant::Arbitrary(_), _) => Ordering::Greater,
+        (_, Variant::Arbitrary(_)) => Ordering::Less,
+        (
+            Variant::Known {
+                order: a_order,
+                detail: a_detail,
+            },
+            Variant::Known {
+                order: b_order,
+                detail: b_detail,
+            },
+        ) => a_order
+            .cmp(b_order)
+            .then_with(|| compare_details(a_detail, b_detail)),
+    }
+}
+
+/// Compare arbitrary variants by their selector text, with `_` standing for a space
+fn compare_selectors(a: &str, b: &str) -> Ordering {
+    let decode = |c: char| if c == '_' { ' ' } else { c };
+    a.chars().map(decode).cmp(b.chars().map(decode))
+}
+
+fn compare_details(a: &Detail, b: &Detail) -> Ordering {
+    match (a, b) {
+        // Unnamed groups come before named ones, which are ordered by name
+        (Detail::Compound(a, a_name), Detail::Compound(b, b_name)) => {
+            compare_parsed(a, b).then_with(|| a_name.cmp(b_name))
+        }
+        // Queries on unnamed containers come before named ones of the same width
+        (
+            Detail::Width {
+                max,
+                px: a,
+                name: a_name,
+            },
+            Detail::Width {
+                px: b,
+                name: b_name,
+                ..
+            },
+        ) => match (a, b) {
+            (Some(a), Some(b)) if *max => b.total_cmp(a),
+            (Some(a), Some(b)) => a.total_cmp(b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+        .then_with(|| a_name.cmp(b_name)),
+        // Named values come before arbitrary ones
+        (Detail::Value(a), Detail::Value(b)) => a
+            .starts_with('[')
+            .cmp(&b.starts_with('['))
+            .then_with(|| a.cmp(b)),
+        _ => Ordering::Equal,
+    }
+}
+
+/// Compare two variants (with or without their trailing colon) in Tailwind v4 order
+pub fn compare_variants(a: &str, b: &str) -> Ordering {
+    let a = a.strip_suffix(':').unwrap_or(a);
+    let b = b.strip_suffix(':').unwrap_or(b);
+
+    compare_parsed(&parse_variant(a), &parse_variant(b)).then_with(|| a.cmp(b))
+}
+
+/// Compare the variant chains of two classes the way Tailwind does.
+///
+/// Tailwind gives each variant a bit by its variant order, so the chain holding the highest
+/// variant the other lacks sorts later. The order variants are written in doesn't matter.
+pub fn compare_variant_chains(a: &[&str], b: &[&str]) -> Ordering {
+    let a = descending(a);
+    let b = descending(b);
+
+    a.iter()
+        .zip(&b)
+        .map(|(a, b)| compare_variants(a, b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.len().cmp(&b.len()))
+}
+
+fn descending<'a>(chain: &[&'a str]) -> Vec<&'a str> {
+    let mut chain = chain.to_vec();
+    chain.sort_by(|a, b| compare_variants(b, a));
+    chain.dedup();
+    chain
+}
+
+/// The variant that dominates a chain's sort order
+pub fn highest_variant<'a>(chain: &[&'a str]) -> Option<&'a str> {
+    chain.iter().copied().max_by(|a, b| compare_variants(a, b))
+}
+
+/// Position of a variant's root in Tailwind's variant order.
+///
+/// Custom variants come after Tailwind's own, then arbitrary variants and finally unknown ones.
+pub fn variant_order(variant: &str) -> usize {
+    let variant = variant.strip_suffix(':').unwrap_or(variant);
+
+    match parse_variant(variant) {
+        Variant::Known { order, .. } => order,
+        Variant::Arbitrary(_) => VARIANT_ORDER.len() + custom_variants_len(),
+        Variant::Unknown(_) => VARIANT_ORDER.len() + custom_variants_len() + 1,
+    }
+}
+
+/// Check whether a variant sorts at or after the breakpoints, which is where the
+/// responsive variant bucket starts
+pub fn sorts_after_breakpoints(variant: &str) -> bool {
+    root_order("max").is_some_and(|breakpoints| variant_order(variant) >= breakpoints)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Class lists paired with how prettier-plugin-tailwindcss sorts them with Tailwind v4
+    const VARIANT_CORPUS: &[(&str, &str)] = &[
+        // Stacked variants
+        (
+            "dark:hover:bg-black hover:bg-white bg-gray-100",
+            "bg-gray-100 hover:bg-white dark:hover:bg-black",
+        ),
+        (
+            "md:hover:underline hover:underline md:underline underline",
+            "underline hover:underline md:underline md:hover:underline",
+        ),
+        (
+            "lg:dark:hover:text-white text-black dark:text-white",
+            "text-black dark:text-white lg:dark:hover:text-white",
+        ),
+        // Arbitrary variants
+        ("[&>*]:p-4 hover:p-2 p-1", "p-1 hover:p-2 [&>*]:p-4"),
+        (
+            "[&_p]:mt-4 [&:hover]:underline",
+            "[&_p]:mt-4 [&:hover]:underline",
+        ),
+        // `group-*` and `peer-*`
+        (
+            "peer-focus:ring-2 group-hover:ring-2 hover:ring-2 ring-1",
+            "ring-1 group-hover:ring-2 peer-focus:ring-2 hover:ring-2",
+        ),
+        (
+            "group-hover/item:visible group-hover:block",
+            "group-hover:block group-hover/item:visible",
+        ),
+        (
+            "group-[.is-open]:block group-hover:block",
+            "group-hover:block group-[.is-open]:block",
+        ),
+        // `not-*` and `has-*`
+        (
+            "has-checked:bg-blue-500 not-first:mt-2 hover:bg-blue-600 bg-white",
+            "bg-white not-first:mt-2 hover:bg-blue-600 has-checked:bg-blue-500",
+        ),
+        (
+            "has-[>img]:p-0 not-last:pb-2",
+            "not-last:pb-2 has-[>img]:p-0",
+        ),
+        // `@container` queries
+        (
+            "@lg:flex-row @md:flex-col flex @container",
+            "@container flex @md:flex-col @lg:flex-row",
+        ),
+        (
+            "@lg/main:grid @sm/main:block lg:flex",
+            "lg:flex @sm/main:block @lg/main:grid",
+        ),
+        // Breakpoints
+        (
+            "xl:w-1/4 lg:w-1/3 md:w-1/2 w-full sm:w-auto",
+            "w-full sm:w-auto md:w-1/2 lg:w-1/3 xl:w-1/4",
+        ),
+        (
+            "md:block max-sm:text-sm max-md:hidden",
+            "max-md:hidden max-sm:text-sm md:block",
+        ),
+        (
+            "min-[900px]:grid 2xl:flex sm:block",
+            "sm:block min-[900px]:grid 2xl:flex",
+        ),
+    ];
+
+    #[test]
+    fn test_variant_corpus_matches_prettier_plugin() {
+        for &(input, expected) in VARIANT_CORPUS {
+            assert_eq!(
+                crate::sort_classes(input),
+                expected,
+                "sorting \"{}\"",
+                input
+            );
+        }
+    }
+
+    fn sorted(variants: &[&'static str]) -> Vec<&'static str> {
+        let mut variants = variants.to_vec();
+        variants.sort_by(|a, b| compare_variants(a, b));
+        variants
+    }
+
+    #[test]
+    fn test_pseudo_classes_follow_registration_order() {
+        assert_eq!(
+            sorted(&[
+                "disabled",
+                "active",
+                "focus",
+                "hover",
+                "focus-within",
+                "odd",
+                "first",
+                "*"
+            ]),
+            vec![
+                "*",
+                "first",
+                "odd",
+                "focus-within",
+                "hover",
+                "focus",
+                "active",
+                "disabled"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compound_variants_order_by_wrapped_variant() {
+        assert_eq!(
+            sorted(&[
+                "peer-hover",
+                "group-focus",
+                "group-hover",
+                "not-first",
+                "has-checked"
+            ]),
+            vec![
+                "not-first",
+                "group-hover",
+                "group-focus",
+                "peer-hover",
+                "has-checked"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_named_groups_follow_unnamed() {
+        assert_eq!(
+            sorted(&[
+                "group-focus",
+                "group-hover/sidebar",
+                "group-hover/item",
+                "group-hover"
+            ]),
+            vec![
+                "group-hover",
+                "group-hover/item",
+                "group-hover/sidebar",
+                "group-focus"
+            ]
+        );
+        assert_eq!(
+            sorted(&["peer-[:checked]/label", "peer-checked/label"]),
+            vec!["peer-checked/label", "peer-[:checked]/label"]
+        );
+    }
+
+    #[test]
+    fn test_functional_variants_order_by_value() {
+        assert_eq!(
+            sorted(&[
+                "data-[state=open]",
+                "data-active",
+                "aria-selected",
+                "aria-checked"
+            ]),
+            vec![
+                "aria-checked",
+                "aria-selected",
+                "data-active",
+                "data-[state=open]"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_breakpoints_order_by_width() {
+        assert_eq!(
+            sorted(&["min-[900px]", "max-sm", "lg", "sm", "max-lg", "md"]),
+            vec!["max-lg", "max-sm", "sm", "md", "min-[900px]", "lg"]
+        );
+    }
+
+    #[test]
+    fn test_theme_breakpoints_extend_or_replace_defaults() {
+        let extended = ThemeWidths {
+            widths: vec![("xs".to_string(), 480.0), ("md".to_string(), 800.0)],
+            ..ThemeWidths::default()
+        };
+        assert_eq!(lookup_width("xs", &extended, BREAKPOINTS), Some(480.0));
+        assert_eq!(lookup_width("md", &extended, BREAKPOINTS), Some(800.0));
+        assert_eq!(lookup_width("lg", &extended, BREAKPOINTS), Some(1024.0));
+
+        let replaced = ThemeWidths {
+            replace_defaults: true,
+            ..extended
+        };
+        assert_eq!(lookup_width("xs", &replaced, BREAKPOINTS), Some(480.0));
+        assert_eq!(lookup_width("lg", &replaced, BREAKPOINTS), None);
+
+        // Screens from the settings win over the theme but keep its replace flag
+        let configured = ThemeWidths {
+            configured: vec![("md".to_string(), 900.0), ("tablet".to_string(), 700.0)],
+            ..replaced
+        };
+        assert_eq!(lookup_width("md", &configured, BREAKPOINTS), Some(900.0));
+        assert_eq!(
+            lookup_width("tablet", &configured, BREAKPOINTS),
+            Some(700.0)
+        );
+        assert_eq!(lookup_width("xs", &configured, BREAKPOINTS), Some(480.0));
+        assert_eq!(lookup_width("lg", &configured, BREAKPOINTS), None);
+    }
+
+    #[test]
+    fn test_container_queries_order_by_width_then_name() {
+        assert_eq!(
+            sorted(&[
+                "@lg/main",
+                "@max-md/main",
+                "@lg",
+                "@sm/sidebar",
+                "@max-md",
+                "xl"
+            ]),
+            vec![
+                "xl",
+                "@max-md",
+                "@max-md/main",
+                "@sm/sidebar",
+                "@lg",
+                "@lg/main"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_media_variants_after_breakpoints() {
+        assert_eq!(
+            sorted(&[
+                "print",
+                "dark",
+                "@md",
+                "2xl",
+                "motion-safe",
+                "supports-grid",
+                "rtl"
+            ]),
+            vec![
+                "supports-grid",
+                "motion-safe",
+                "2xl",
+                "@md",
+                "rtl",
+                "dark",
+                "print"
+            ]
+        );
+        assert!(sorts_after_breakpoints("dark:"));
+        assert!(sorts_after_breakpoints("max-md:"));
+        assert!(!sorts_after_breakpoints("contrast-more:"));
+    }
+
+    #[test]
+    fn test_parametric_variants() {
+        assert_eq!(
+            sorted(&[
+                "has-[>img]",
+                "peer-[:checked]",
+                "group-hover",
+                "group-[.is-open]"
+            ]),
+            vec![
+                "group-hover",
+                "group-[.is-open]",
+                "peer-[:checked]",
+                "has-[>img]"
+            ]
+        );
+        assert_eq!(
+            sorted(&[
+                "supports-[display:grid]",
+                "aria-[sort=ascending]",
+                "aria-busy"
+            ]),
+            vec![
+                "aria-busy",
+                "aria-[sort=ascending]",
+                "supports-[display:grid]"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_arbitrary_variants_order_by_selector() {
+        assert_eq!(
+            sorted(&["[@media(min-width:900px)]", "[&_p]", "[&>*]", "[&:hover]"]),
+            vec!["[&_p]", "[&:hover]", "[&>*]", "[@media(min-width:900px)]"]
+        );
+    }
+
+    #[test]
+    fn test_variant_chains_compare_by_highest_variant() {
+        assert_eq!(
+            compare_variant_chains(&["md", "hover"], &["hover", "md"]),
+            Ordering::Equal
+        );
+        assert_eq!(
+            compare_variant_chains(&["md", "hover"], &["md"]),
+            Ordering::Greater
+        );
+        assert_eq!(
+            compare_variant_chains(&["md", "hover"], &["md", "focus"]),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare_variant_chains(&["lg"], &["dark", "hover"]),
+            Ordering::Less
+        );
+        assert_eq!(highest_variant(&["hover", "dark", "md"]), Some("dark"));
+    }
+
+    #[test]
+    fn test_arbitrary_and_unknown_variants_last() {
+        assert_eq!(
+            sorted(&["unknown", "[&>*]", "noscript", "hover"]),
+            vec!["hover", "noscript", "[&>*]", "unknown"]
+        );
+    }
+}
@@ -0,0 +1,301 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use js_sys::Function;
+use wasm_bindgen::prelude::*;
+
+use crate::check::check_classes;
+use crate::diff::{DiffOptions, diff_document};
+use crate::explain::explain_classes;
+use crate::extractor::{set_class_attributes, set_class_functions};
+use crate::logging::LogLevel;
+use crate::logging::{Logger, NoopLogger, set_logger, set_max_level};
+use crate::ranges::{TextEdit, TextRange};
+use crate::report::{ReportFormat, find_unsorted, render_report};
+use crate::sorter::{
+    OutputMode, set_normalize_whitespace, set_output_mode, set_print_width, set_remove_duplicates,
+};
+use crate::tailwind_config;
+use crate::theme::load_css_theme;
+use crate::variants::{length_in_px, set_configured_screens};
+use crate::{class_edits_in_ranges, sort_classes_in_ranges, sort_document};
+use crate::{info_log, warn_log};
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = console, js_name = error)]
+    fn console_error(s: &str);
+    #[wasm_bindgen(js_namespace = console, js_name = warn)]
+    fn console_warn(s: &str);
+    #[wasm_bindgen(js_namespace = console, js_name = log)]
+    fn console_log(s: &str);
+}
+
+thread_local! {
+    /// JavaScript function receiving `(level, message)` for every logged message
+    static LOG_CALLBACK: RefCell<Option<Function>> = const { RefCell::new(None) };
+}
+
+/// Logs to the JavaScript console (the default logger in wasm builds)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ConsoleLogger;
+
+impl Logger for ConsoleLogger {
+    #[cfg(target_arch = "wasm32")]
+    fn log(&self, level: LogLevel, message: &str) {
+        match level {
+            LogLevel::Error => console_error(message),
+            LogLevel::Warn => console_warn(message),
+            LogLevel::Info => console_log(message),
+            LogLevel::Debug | LogLevel::Trace => {
+                console_log(&format!("[{}] {}", level.as_str().to_uppercase(), message))
+            }
+        }
+    }
+
+    /// There is no JavaScript console outside wasm
+    #[cfg(not(target_arch = "wasm32"))]
+    fn log(&self, _level: LogLevel, _message: &str) {}
+}
+
+/// Forwards messages to the callback registered with `set_log_callback`.
+///
+/// The callback lives in a thread local because JavaScript values cannot be shared
+/// across threads; wasm runs single threaded, so this is the only thread.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CallbackLogger;
+
+impl Logger for CallbackLogger {
+    fn log(&self, level: LogLevel, message: &str) {
+        LOG_CALLBACK.with(|callback| {
+            if let Some(callback) = callback.borrow().as_ref() {
+                // A throwing callback must not break sorting
+                let _ = callback.call2(
+                    &JsValue::NULL,
+                    &JsValue::from_str(level.as_str()),
+                    &JsValue::from_str(message),
+                );
+            }
+        });
+    }
+}
+
+/// Route log messages to a JavaScript `(level, message) => void` callback.
+///
+/// Passing `undefined` switches back to the console.
+#[wasm_bindgen]
+pub fn set_log_callback(callback: Option<Function>) {
+    let has_callback = callback.is_some();
+    LOG_CALLBACK.with(|current| *current.borrow_mut() = callback);
+
+    if has_callback {
+        set_logger(Box::new(CallbackLogger));
+    } else {
+        set_logger(Box::new(ConsoleLogger));
+    }
+}
+
+/// Set the most verbose level that is logged: `error`, `warn`, `info`, `debug`, `trace` or `off`
+#[wasm_bindgen]
+pub fn set_log_level(level: &str) -> Result<(), JsValue> {
+    if level == "off" {
+        set_max_level(None);
+        return Ok(());
+    }
+
+    let level = LogLevel::from_name(level)
+        .ok_or_else(|| JsValue::from_str(&format!("Unknown log level \"{}\"", level)))?;
+    set_max_level(Some(level));
+    Ok(())
+}
+
+/// Turn logging to the JavaScript console on or off (on by default)
+#[wasm_bindgen]
+pub fn set_console_logging(enabled: bool) {
+    if enabled {
+        set_logger(Box::new(ConsoleLogger));
+    } else {
+        set_logger(Box::new(NoopLogger));
+    }
+}
+
+/// Configure sorting. `debug_mode` is only logged: the log level is owned by `set_log_level`
+#[wasm_bindgen]
+pub fn configure_tailwind_sorter(
+    remove_duplicates: bool,
+    debug_mode: bool,
+    normalize_whitespace: bool,
+    print_width: usize,
+    output_mode: &str,
+) {
+    info_log!(
+        "Configuring Tailwind sorter with remove_duplicates={}, debug_mode={}, normalize_whitespace={}, print_width={}, output_mode={}",
+        remove_duplicates,
+        debug_mode,
+        normalize_whitespace,
+        print_width,
+        output_mode
+    );
+    set_remove_duplicates(remove_duplicates);
+    set_normalize_whitespace(normalize_whitespace);
+    set_print_width(print_width);
+    set_output_mode(OutputMode::from_name(output_mode).unwrap_or_else(|| {
+        warn_log!("Unknown output mode \"{}\", using flat output", output_mode);
+        OutputMode::Flat
+    }));
+}
+
+/// Set the extra JSX attributes and function or tag names whose string literals are always
+/// sorted, mirroring Prettier's `tailwindAttributes` and `tailwindFunctions` options
+#[wasm_bindgen]
+pub fn configure_class_locations(attributes: Vec<String>, functions: Vec<String>) {
+    info_log!(
+        "Configuring class locations with attributes={:?}, functions={:?}",
+        attributes,
+        functions
+    );
+    set_class_attributes(attributes);
+    set_class_functions(functions);
+}
+
+/// Set the project's screens as `{ name: minWidth }` with widths like `"480px"` or `"30rem"`.
+///
+/// They add to or override the breakpoints of Tailwind's defaults and of any loaded theme,
+/// deciding both which variants are responsive and how they are ordered. An empty object leaves
+/// the theme's breakpoints as they are.
+#[wasm_bindgen]
+pub fn configure_screens(screens: JsValue) -> Result<(), JsValue> {
+    let screens: BTreeMap<String, String> = serde_wasm_bindgen::from_value(screens)?;
+    if !screens.is_empty() {
+        info_log!("Configuring screens {:?}", screens);
+    }
+
+    let breakpoints = screens
+        .into_iter()
+        .filter_map(|(name, width)| match length_in_px(&width) {
+            Some(px) => Some((name, px)),
+            None => {
+                warn_log!("Can't order screen {} with width \"{}\"", name, width);
+                None
+            }
+        })
+        .collect();
+    set_configured_screens(breakpoints);
+    Ok(())
+}
+
+#[wasm_bindgen]
+pub fn sort_tailwind_classes(document: &str, file_extension: &str) -> String {
+    sort_document(document, file_extension)
+}
+
+/// Report unsorted class strings without rewriting the document.
+///
+/// Returns an array of `{ start, end, line, column, original, expected }` objects, and throws
+/// when the document can't be parsed.
+#[wasm_bindgen]
+pub fn check_tailwind_classes(document: &str, file_extension: &str) -> Result<JsValue, JsValue> {
+    let unsorted = check_classes(document, file_extension)
+        .map_err(|error| JsValue::from_str(&error.to_string()))?;
+    Ok(serde_wasm_bindgen::to_value(&unsorted)?)
+}
+
+/// Explain where each class of a class string lands and why, in sorted order.
+///
+/// Returns an array of `{ class, category, variant, variantOrder, prefix, propertyOrder }`
+/// objects, where `prefix` is `{ prefix, order, valueTypes, isStandalone }` or `null`.
+#[wasm_bindgen]
+pub fn explain_sort(class_string: &str) -> Result<JsValue, JsValue> {
+    let explanations = explain_classes(class_string);
+    Ok(serde_wasm_bindgen::to_value(&explanations)?)
+}
+
+/// Load `@theme`, `@utility`, `@custom-variant` and `@plugin` rules from a Tailwind CSS entry
+/// file for the rest of the session, returning what was registered and any warnings
+#[wasm_bindgen]
+pub fn load_tailwind_css(css: &str) -> Result<JsValue, JsValue> {
+    let theme = load_css_theme(css);
+    Ok(serde_wasm_bindgen::to_value(&theme)?)
+}
+
+/// Load the theme, `prefix` and `separator` of a `tailwind.config.{js,ts}` file
+/// for the rest of the session without running it, returning what was read and any warnings
+#[wasm_bindgen]
+pub fn load_tailwind_config(source: &str, file_extension: &str) -> Result<JsValue, JsValue> {
+    let theme = tailwind_config::load_tailwind_config(source, file_extension);
+    Ok(serde_wasm_bindgen::to_value(&theme)?)
+}
+
+/// Render the changes sorting would make as a plain unified diff (empty if already sorted)
+#[wasm_bindgen]
+pub fn diff_tailwind_classes(document: &str, file_extension: &str, file_name: &str) -> String {
+    diff_document(document, file_extension, file_name, &DiffOptions::default())
+}
+
+/// Report unsorted class strings as `json` or `sarif` (SARIF 2.1.0) for a document at `path`
+#[wasm_bindgen]
+pub fn report_tailwind_classes(
+    document: &str,
+    file_extension: &str,
+    path: &str,
+    format: &str,
+) -> Result<String, JsValue> {
+    let format = ReportFormat::from_name(format)
+        .ok_or_else(|| JsValue::from_str(&format!("Unknown report format \"{}\"", format)))?;
+
+    let findings = find_unsorted(document, file_extension, path)
+        .map_err(|error| JsValue::from_str(&error.to_string()))?;
+    Ok(render_report(&findings, format))
+}
+
+/// Sort only the class strings that intersect a single range.
+///
+/// Offsets are UTF-16 code units when `utf16` is set (as used by editors), bytes otherwise.
+#[wasm_bindgen]
+pub fn sort_tailwind_classes_in_range(
+    document: &str,
+    file_extension: &str,
+    start: usize,
+    end: usize,
+    utf16: bool,
+) -> String {
+    let range = TextRange { start, end };
+    let range = if utf16 {
+        range.to_byte_offsets(document)
+    } else {
+        range
+    };
+
+    sort_classes_in_ranges(document, file_extension, &[range])
+}
+
+/// Sort only the class strings that intersect any of the given `{ start, end }` ranges,
+/// e.g. the lines changed since the last save.
+///
+/// Returns one `{ start, end, replacement }` edit per rewritten class string, ordered from
+/// end to start, so text outside the class strings is never touched. Offsets are UTF-16
+/// code units when `utf16` is set (as used by editors), bytes otherwise.
+#[wasm_bindgen]
+pub fn sort_tailwind_classes_in_ranges(
+    document: &str,
+    file_extension: &str,
+    ranges: JsValue,
+    utf16: bool,
+) -> Result<JsValue, JsValue> {
+    let ranges: Vec<TextRange> = serde_wasm_bindgen::from_value(ranges)?;
+    let ranges: Vec<TextRange> = if utf16 {
+        ranges.iter().map(|r| r.to_byte_offsets(document)).collect()
+    } else {
+        ranges
+    };
+
+    let edits = class_edits_in_ranges(document, file_extension, &ranges);
+    let edits: Vec<TextEdit> = if utf16 {
+        edits.iter().map(|e| e.to_utf16_offsets(document)).collect()
+    } else {
+        edits
+    };
+
+    Ok(serde_wasm_bindgen::to_value(&edits)?)
+}
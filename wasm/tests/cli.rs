@@ -0,0 +1,120 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+/// Create an empty scratch directory for one test
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("tailwind-sort-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn tailwind_sort(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_tailwind-sort"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_check_fails_on_syntax_error() {
+    let dir = scratch_dir("syntax-error");
+    let bad = dir.join("bad.tsx");
+    let good = dir.join("good.tsx");
+    fs::write(&bad, "export const A = () => <div className=\"p-4 flex\"\n").unwrap();
+    fs::write(
+        &good,
+        "export const B = () => <div className=\"flex p-4\" />;\n",
+    )
+    .unwrap();
+
+    let output = tailwind_sort(&["--check", bad.to_str().unwrap(), good.to_str().unwrap()]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr.contains(&format!("{}: parse error", bad.display())));
+    assert!(!stderr.contains(&format!("{}:", good.display())));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_unsupported_extension_is_an_error() {
+    let dir = scratch_dir("unsupported");
+    let notes = dir.join("notes.txt");
+    fs::write(&notes, "p-4 flex\n").unwrap();
+
+    let output = tailwind_sort(&["--check", notes.to_str().unwrap()]);
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unsupported file extension"));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_color_reset_makes_palette_colors_custom() {
+    let dir = scratch_dir("color-reset");
+    let css = dir.join("app.css");
+    let component = dir.join("a.tsx");
+    fs::write(
+        &css,
+        "@import \"tailwindcss\";\n@theme {\n  --color-*: initial;\n  --color-brand: #0af;\n}\n",
+    )
+    .unwrap();
+    fs::write(
+        &component,
+        "export const A = () => <div className=\"p-4 bg-brand flex bg-red-500\" />;\n",
+    )
+    .unwrap();
+
+    let output = tailwind_sort(&["--css", css.to_str().unwrap(), component.to_str().unwrap()]);
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "export const A = () => <div className=\"bg-red-500 flex bg-brand p-4\" />;\n"
+    );
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_config_and_css_themes_combine() {
+    let dir = scratch_dir("config-and-css");
+    let config = dir.join("tailwind.config.js");
+    let css = dir.join("app.css");
+    let component = dir.join("a.tsx");
+    fs::write(
+        &config,
+        "module.exports = {\n  prefix: \"tw-\",\n  theme: { extend: { screens: { tablet: \"640px\" } } },\n};\n",
+    )
+    .unwrap();
+    fs::write(
+        &css,
+        "@import \"tailwindcss\";\n@theme {\n  --color-brand: #0af;\n}\n",
+    )
+    .unwrap();
+    fs::write(
+        &component,
+        "export const A = () => <div className=\"md:tw-p-1 tw-p-4 tablet:tw-p-2 tw-bg-brand custom tw-flex\" />;\n",
+    )
+    .unwrap();
+
+    let output = tailwind_sort(&[
+        "--config",
+        config.to_str().unwrap(),
+        "--css",
+        css.to_str().unwrap(),
+        component.to_str().unwrap(),
+    ]);
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "export const A = () => <div className=\"custom tw-flex tw-bg-brand tw-p-4 tablet:tw-p-2 md:tw-p-1\" />;\n"
+    );
+
+    let _ = fs::remove_dir_all(&dir);
+}